@@ -5,12 +5,16 @@ use rustc::lint::*;
 use rustc::hir::*;
 use rustc::ty;
 use semver::Version;
+use std::ascii::AsciiExt;
+use syntax::abi::Abi;
 use syntax::ast::{Attribute, Lit, LitKind, MetaItemKind, NestedMetaItem, NestedMetaItemKind};
 use syntax::codemap::Span;
-use utils::{in_macro, match_def_path, paths, span_lint, span_lint_and_then, snippet_opt};
+use utils::{in_macro, match_def_path, paths, span_help_and_lint, span_lint, span_lint_and_then, span_note_and_lint,
+            snippet_opt};
 
 /// **What it does:** Checks for items annotated with `#[inline(always)]`,
-/// unless the annotated function is empty or simply panics.
+/// unless the annotated function is empty, simply panics, only forwards to
+/// another function or is also marked `#[cold]`.
 ///
 /// **Why is this bad?** While there are valid uses of this annotation (and once
 /// you know when to use it, by all means `allow` this lint), it's a common
@@ -35,10 +39,14 @@ declare_lint! {
     "use of `#[inline(always)]`"
 }
 
-/// **What it does:** Checks for `extern crate` and `use` items annotated with lint attributes
+/// **What it does:** Checks for `extern crate` and `use` items annotated with lint attributes,
+/// `#[path]` on items other than `mod`s and `#[no_mangle]` on private functions that are not
+/// `extern`.
 ///
 /// **Why is this bad?** Lint attributes have no effect on crate imports. Most likely a `!` was
-/// forgotten
+/// forgotten. `#[path]` is ignored on anything but modules. A private `#[no_mangle]` function
+/// without an `extern` ABI exposes an unmangled symbol with the unstable Rust calling convention,
+/// which nothing outside the crate can reliably call.
 ///
 /// **Known problems:** Technically one might allow `unused_import` on a `use` item,
 /// but it's easier to remove the unused item.
@@ -49,15 +57,20 @@ declare_lint! {
 /// extern crate foo;
 /// #[allow(unused_import)]
 /// use foo::bar;
+/// #[path = "bar.rs"]
+/// fn bar() {}
+/// #[no_mangle]
+/// fn baz() {}
 /// ```
 declare_lint! {
     pub USELESS_ATTRIBUTE,
     Warn,
-    "use of lint attributes on `extern crate` items"
+    "use of attributes where they have no effect, e.g. lint attributes on `extern crate` items"
 }
 
 /// **What it does:** Checks for `#[deprecated]` annotations with a `since`
-/// field that is not a valid semantic version.
+/// field that is not a valid semantic version. Common placeholders like `"TBD"`
+/// are left to `deprecated_semver_placeholder`.
 ///
 /// **Why is this bad?** For checking the version of the deprecation, it must be
 /// a valid semver. Failing that, the contained information is useless.
@@ -75,12 +88,55 @@ declare_lint! {
     "use of `#[deprecated(since = \"x\")]` where x is not semver"
 }
 
+/// **What it does:** Checks for `#[deprecated]` annotations with a placeholder
+/// like `"TBD"`, `"unreleased"` or `"next"` as `since` field.
+///
+/// **Why is this bad?** The placeholder is meant to be replaced by the actual
+/// version before releasing, which is easy to forget.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[deprecated(since = "TBD")]
+/// fn something_else(..) { ... }
+/// ```
+declare_lint! {
+    pub DEPRECATED_SEMVER_PLACEHOLDER,
+    Allow,
+    "use of `#[deprecated(since = \"TBD\")]` or similar placeholders"
+}
+
+/// **What it does:** Checks for `#[should_panic]` on test functions without
+/// an `expected = ".."` message.
+///
+/// **Why is this bad?** Such a test passes whenever the function panics, even
+/// if it panics for a completely different reason than the one being tested.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// #[test]
+/// #[should_panic]
+/// fn test_overflow() { ... }
+/// ```
+declare_lint! {
+    pub SHOULD_PANIC_WITHOUT_EXPECTED,
+    Allow,
+    "use of `#[should_panic]` on a test without an `expected` message"
+}
+
 #[derive(Copy,Clone)]
 pub struct AttrPass;
 
 impl LintPass for AttrPass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(INLINE_ALWAYS, DEPRECATED_SEMVER, USELESS_ATTRIBUTE)
+        lint_array!(INLINE_ALWAYS,
+                    DEPRECATED_SEMVER,
+                    DEPRECATED_SEMVER_PLACEHOLDER,
+                    USELESS_ATTRIBUTE,
+                    SHOULD_PANIC_WITHOUT_EXPECTED)
     }
 }
 
@@ -106,6 +162,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for AttrPass {
         if is_relevant_item(cx.tcx, item) {
             check_attrs(cx, item.span, &item.name, &item.attrs)
         }
+        check_misplaced_attrs(cx, item);
+        check_should_panic(cx, item);
         match item.node {
             ItemExternCrate(_) |
             ItemUse(_, _) => {
@@ -184,6 +242,9 @@ fn is_relevant_trait(tcx: ty::TyCtxt, item: &TraitItem) -> bool {
 }
 
 fn is_relevant_block(tcx: ty::TyCtxt, tables: &ty::TypeckTables, block: &Block) -> bool {
+    if is_forwarding_block(block) {
+        return false;
+    }
     for stmt in &block.stmts {
         match stmt.node {
             StmtDecl(_, _) => return true,
@@ -214,11 +275,45 @@ fn is_relevant_expr(tcx: ty::TyCtxt, tables: &ty::TypeckTables, expr: &Expr) ->
     }
 }
 
+/// Checks if the block only consists of a single call whose arguments are all paths or
+/// literals, e.g. `{ g(x) }` or `{ self.0.h(); }`.
+fn is_forwarding_block(block: &Block) -> bool {
+    let expr = match (&*block.stmts, &block.expr) {
+        (&[], &Some(ref expr)) => &**expr,
+        (&[ref stmt], &None) => {
+            match stmt.node {
+                StmtSemi(ref expr, _) => &**expr,
+                _ => return false,
+            }
+        },
+        _ => return false,
+    };
+    let args = match expr.node {
+        ExprCall(ref fun, ref args) if is_trivial_arg(fun) => args,
+        ExprMethodCall(_, _, ref args) => args,
+        _ => return false,
+    };
+    args.iter().all(is_trivial_arg)
+}
+
+fn is_trivial_arg(expr: &Expr) -> bool {
+    match expr.node {
+        ExprPath(_) | ExprLit(_) => true,
+        ExprAddrOf(_, ref e) | ExprField(ref e, _) | ExprTupField(ref e, _) => is_trivial_arg(e),
+        _ => false,
+    }
+}
+
 fn check_attrs(cx: &LateContext, span: Span, name: &Name, attrs: &[Attribute]) {
     if in_macro(span) {
         return;
     }
 
+    // `#[inline(always)]` together with `#[cold]` is a deliberate choice
+    if attrs.iter().any(|attr| attr.check_name("cold")) {
+        return;
+    }
+
     for attr in attrs {
         if let Some(ref values) = attr.meta_item_list() {
             if values.len() != 1 || attr.name().map_or(true, |n| n != "inline") {
@@ -235,16 +330,80 @@ fn check_attrs(cx: &LateContext, span: Span, name: &Name, attrs: &[Attribute]) {
     }
 }
 
+/// Check for `#[path]` and `#[no_mangle]` on items where they make no sense.
+fn check_misplaced_attrs(cx: &LateContext, item: &Item) {
+    if in_macro(item.span) {
+        return;
+    }
+
+    for attr in &item.attrs {
+        if let Some(name) = attr.name() {
+            match (&*name.as_str(), &item.node) {
+                ("path", &ItemMod(_)) => (),
+                ("path", _) => {
+                    span_lint(cx, USELESS_ATTRIBUTE, attr.span, "`#[path]` only has an effect on `mod` items");
+                },
+                ("no_mangle", &ItemFn(_, _, _, Abi::Rust, _, _)) if item.vis != Visibility::Public => {
+                    span_lint(cx,
+                              USELESS_ATTRIBUTE,
+                              attr.span,
+                              &format!("`#[no_mangle]` on `{}`, which does not use an `extern` ABI", item.name));
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Check for `#[should_panic]` without `expected` on test functions.
+fn check_should_panic(cx: &LateContext, item: &Item) {
+    if !item.attrs.iter().any(|attr| attr.check_name("test")) {
+        return;
+    }
+
+    for attr in &item.attrs {
+        if !attr.check_name("should_panic") {
+            continue;
+        }
+        // `#[should_panic = ".."]` is a shorthand for `expected`
+        let has_expected = attr.value_str().is_some() || attr.meta_item_list().map_or(false, |items| {
+            items.iter().any(|item| item.meta_item().map_or(false, |mi| mi.name() == "expected"))
+        });
+        if !has_expected {
+            span_help_and_lint(cx,
+                               SHOULD_PANIC_WITHOUT_EXPECTED,
+                               attr.span,
+                               &format!("`#[should_panic]` on `{}` without an expected panic message", item.name),
+                               "consider checking the message with `#[should_panic(expected = \"..\")]`");
+        }
+    }
+}
+
+/// Placeholders deliberately used in `#[deprecated(since = "..")]` before the actual version is
+/// known.
+const SEMVER_PLACEHOLDERS: [&'static str; 3] = ["TBD", "unreleased", "next"];
+
 fn check_semver(cx: &LateContext, span: Span, lit: &Lit) {
     if let LitKind::Str(ref is, _) = lit.node {
-        if Version::parse(&is.as_str()).is_ok() {
+        let version = is.as_str();
+        if Version::parse(&version).is_ok() {
+            return;
+        }
+        if SEMVER_PLACEHOLDERS.iter().any(|p| p.eq_ignore_ascii_case(&version)) {
+            span_note_and_lint(cx,
+                               DEPRECATED_SEMVER_PLACEHOLDER,
+                               span,
+                               &format!("the since field contains the placeholder `{}`", version),
+                               span,
+                               "remember to replace it with the actual version before releasing");
             return;
         }
     }
-    span_lint(cx,
-              DEPRECATED_SEMVER,
-              span,
-              "the since field must contain a semver-compliant version");
+    span_help_and_lint(cx,
+                       DEPRECATED_SEMVER,
+                       span,
+                       "the since field must contain a semver-compliant version",
+                       "a valid version looks like `since = \"1.2.3\"`");
 }
 
 fn is_word(nmi: &NestedMetaItem, expected: &str) -> bool {
@@ -3,8 +3,8 @@ use rustc::hir::*;
 use rustc::hir::intravisit::{Visitor, walk_expr, NestedVisitorMap};
 use utils::*;
 
-/// **What it does:** Checks for `if` conditions that use blocks to contain an
-/// expression.
+/// **What it does:** Checks for `if` and `while` conditions that use blocks to
+/// contain an expression.
 ///
 /// **Why is this bad?** It isn't really Rust style, same as using parentheses
 /// to contain expressions.
@@ -21,8 +21,8 @@ declare_lint! {
     "braces that can be eliminated in conditions, e.g. `if { true } ...`"
 }
 
-/// **What it does:** Checks for `if` conditions that use blocks containing
-/// statements, or conditions that use closures with blocks.
+/// **What it does:** Checks for `if` and `while` conditions that use blocks
+/// containing statements, or conditions that use closures with blocks.
 ///
 /// **Why is this bad?** Using blocks in the condition makes it hard to read.
 ///
@@ -74,52 +74,80 @@ impl<'a, 'tcx: 'a> Visitor<'tcx> for ExVisitor<'a, 'tcx> {
 const BRACED_EXPR_MESSAGE: &'static str = "omit braces around single expression condition";
 const COMPLEX_BLOCK_MESSAGE: &'static str = "in an 'if' condition, avoid complex blocks or closures with blocks; \
                                              instead, move the block or closure higher and bind it with a 'let'";
+const COMPLEX_WHILE_BLOCK_MESSAGE: &'static str = "in a 'while' condition, avoid complex blocks or closures with \
+                                                   blocks; instead, move the block or closure into a separate function";
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BlockInIfCondition {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let ExprIf(ref check, ref then, _) = expr.node {
-            if let ExprBlock(ref block) = check.node {
-                if block.rules == DefaultBlock {
-                    if block.stmts.is_empty() {
-                        if let Some(ref ex) = block.expr {
-                            // don't dig into the expression here, just suggest that they remove
-                            // the block
-                            if in_macro(expr.span) || differing_macro_contexts(expr.span, ex.span) {
-                                return;
-                            }
-                            span_help_and_lint(cx,
-                                               BLOCK_IN_IF_CONDITION_EXPR,
-                                               check.span,
-                                               BRACED_EXPR_MESSAGE,
-                                               &format!("try\nif {} {} ... ",
-                                                        snippet_block(cx, ex.span, ".."),
-                                                        snippet_block(cx, then.span, "..")));
-                        }
-                    } else {
-                        let span = block.expr.as_ref().map_or_else(|| block.stmts[0].span, |e| e.span);
-                        if in_macro(span) || differing_macro_contexts(expr.span, span) {
-                            return;
-                        }
-                        // move block higher
-                        span_help_and_lint(cx,
-                                           BLOCK_IN_IF_CONDITION_STMT,
-                                           check.span,
-                                           COMPLEX_BLOCK_MESSAGE,
-                                           &format!("try\nlet res = {};\nif res {} ... ",
-                                                    snippet_block(cx, block.span, ".."),
-                                                    snippet_block(cx, then.span, "..")));
+        match expr.node {
+            ExprIf(ref check, ref then, _) => check_condition(cx, expr, check, then, "if"),
+            ExprWhile(ref check, ref body, _) => check_condition(cx, expr, check, body, "while"),
+            _ => (),
+        }
+    }
+}
+
+/// Check the condition `check` of the `if` or `while` expression `expr`, `keyword` being the
+/// corresponding keyword.
+fn check_condition<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    expr: &'tcx Expr,
+    check: &'tcx Expr,
+    then: &'tcx Block,
+    keyword: &str
+) {
+    if let ExprBlock(ref block) = check.node {
+        if block.rules == DefaultBlock {
+            if block.stmts.is_empty() {
+                if let Some(ref ex) = block.expr {
+                    // don't dig into the expression here, just suggest that they remove
+                    // the block
+                    if in_macro(expr.span) || differing_macro_contexts(expr.span, ex.span) {
+                        return;
                     }
+                    span_help_and_lint(cx,
+                                       BLOCK_IN_IF_CONDITION_EXPR,
+                                       check.span,
+                                       BRACED_EXPR_MESSAGE,
+                                       &format!("try\n{} {} {} ... ",
+                                                keyword,
+                                                snippet_block(cx, ex.span, ".."),
+                                                snippet_block(cx, then.span, "..")));
                 }
             } else {
-                let mut visitor = ExVisitor {
-                    found_block: None,
-                    cx: cx,
-                };
-                walk_expr(&mut visitor, check);
-                if let Some(block) = visitor.found_block {
-                    span_lint(cx, BLOCK_IN_IF_CONDITION_STMT, block.span, COMPLEX_BLOCK_MESSAGE);
+                let span = block.expr.as_ref().map_or_else(|| block.stmts[0].span, |e| e.span);
+                if in_macro(span) || differing_macro_contexts(expr.span, span) {
+                    return;
                 }
+                if keyword == "while" {
+                    // the condition is evaluated on each iteration, it can't be bound once before
+                    // the loop
+                    span_lint(cx, BLOCK_IN_IF_CONDITION_STMT, check.span, COMPLEX_WHILE_BLOCK_MESSAGE);
+                    return;
+                }
+                // move block higher
+                span_help_and_lint(cx,
+                                   BLOCK_IN_IF_CONDITION_STMT,
+                                   check.span,
+                                   COMPLEX_BLOCK_MESSAGE,
+                                   &format!("try\nlet res = {};\nif res {} ... ",
+                                            snippet_block(cx, block.span, ".."),
+                                            snippet_block(cx, then.span, "..")));
             }
         }
+    } else {
+        let mut visitor = ExVisitor {
+            found_block: None,
+            cx: cx,
+        };
+        walk_expr(&mut visitor, check);
+        if let Some(block) = visitor.found_block {
+            let message = if keyword == "while" {
+                COMPLEX_WHILE_BLOCK_MESSAGE
+            } else {
+                COMPLEX_BLOCK_MESSAGE
+            };
+            span_lint(cx, BLOCK_IN_IF_CONDITION_STMT, block.span, message);
+        }
     }
 }
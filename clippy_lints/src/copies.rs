@@ -6,7 +6,7 @@ use std::collections::hash_map::Entry;
 use syntax::symbol::InternedString;
 use syntax::util::small_vector::SmallVector;
 use utils::{SpanlessEq, SpanlessHash};
-use utils::{get_parent_expr, in_macro, span_lint_and_then, span_note_and_lint, snippet};
+use utils::{get_parent_expr, in_macro, span_lint_and_then, span_lint_many, span_note_and_lint, snippet};
 
 /// **What it does:** Checks for consecutive `if`s with the same condition.
 ///
@@ -157,13 +157,9 @@ fn lint_same_cond(cx: &LateContext, conds: &[&Expr]) {
 
     let eq: &Fn(&&Expr, &&Expr) -> bool = &|&lhs, &rhs| -> bool { SpanlessEq::new(cx).ignore_fn().eq_expr(lhs, rhs) };
 
-    if let Some((i, j)) = search_same(conds, hash, eq) {
-        span_note_and_lint(cx,
-                           IFS_SAME_COND,
-                           j.span,
-                           "this `if` has the same condition as a previous if",
-                           i.span,
-                           "same as this");
+    if let Some((i, _)) = search_same(conds, hash, eq) {
+        let spans = conds.iter().filter(|&cond| eq(i, cond)).map(|cond| cond.span).collect::<Vec<_>>();
+        span_lint_many(cx, IFS_SAME_COND, &spans, "these `if`s have the same condition");
     }
 }
 
@@ -0,0 +1,98 @@
+//! lint on `if` and `else` blocks which are empty
+
+use rustc::lint::*;
+use syntax::ast::*;
+
+use utils::{in_macro, snippet_opt, span_help_and_lint};
+
+/// **What it does:** Checks for `if` expressions whose then block or else block
+/// is empty.
+///
+/// **Why is this bad?** An empty branch is either dead code or a sign that
+/// something was left unfinished. The condition can be inverted to get rid of
+/// an empty then block, and an empty else block can be removed.
+///
+/// **Known problems:** Blocks only containing comments are not linted, as the
+/// comment usually explains why nothing is done.
+///
+/// **Example:**
+/// ```rust
+/// if x {
+/// } else {
+///     a()
+/// }
+/// ```
+///
+/// Could be written:
+///
+/// ```rust
+/// if !x {
+///     a()
+/// }
+/// ```
+declare_lint! {
+    pub EMPTY_IF_BRANCH,
+    Allow,
+    "`if` expressions with an empty then or else block"
+}
+
+pub struct EmptyIfBranch;
+
+impl LintPass for EmptyIfBranch {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EMPTY_IF_BRANCH)
+    }
+}
+
+impl EarlyLintPass for EmptyIfBranch {
+    fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::If(_, ref then, ref els) = expr.node {
+            let els = match *els {
+                Some(ref els) => {
+                    match els.node {
+                        ExprKind::Block(ref els) => Some(els),
+                        // `else if` chains cannot simply be inverted
+                        _ => return,
+                    }
+                },
+                None => None,
+            };
+
+            match (is_empty_block(cx, then), els) {
+                (true, Some(els)) if !is_empty_block(cx, els) => {
+                    span_help_and_lint(cx,
+                                       EMPTY_IF_BRANCH,
+                                       then.span,
+                                       "this `if` has an empty then block",
+                                       "invert the condition and move the `else` block in its place");
+                },
+                (true, _) => {
+                    span_help_and_lint(cx,
+                                       EMPTY_IF_BRANCH,
+                                       expr.span,
+                                       "this `if` has no effect besides evaluating its condition",
+                                       "remove it, keeping the condition as a statement if it has side effects");
+                },
+                (false, Some(els)) if is_empty_block(cx, els) => {
+                    span_help_and_lint(cx,
+                                       EMPTY_IF_BRANCH,
+                                       els.span,
+                                       "this `else` block is empty",
+                                       "remove the `else` block");
+                },
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Is `block` empty, not even containing a comment?
+fn is_empty_block(cx: &EarlyContext, block: &Block) -> bool {
+    block.stmts.is_empty() &&
+    snippet_opt(cx, block.span).map_or(false, |snippet| {
+        snippet.trim_left_matches('{').trim_right_matches('}').trim().is_empty()
+    })
+}
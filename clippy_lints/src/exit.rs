@@ -0,0 +1,50 @@
+use rustc::hir::{Expr, ExprCall, ExprPath};
+use rustc::lint::*;
+use utils::{match_def_path, paths, span_lint};
+
+/// **What it does:** Checks for calls to `std::process::exit` outside of the
+/// `main` function.
+///
+/// **Why is this bad?** A function which may exit the process cannot be
+/// tested, and leaves no chance to its callers to handle the error.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn check(x: u32) {
+///     if x == 0 {
+///         std::process::exit(1);
+///     }
+/// }
+/// ```
+declare_restriction_lint! {
+    pub EXIT,
+    "`std::process::exit` is called outside of `main`"
+}
+
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(EXIT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if let ExprCall(ref path_expr, _) = e.node {
+            if let ExprPath(ref qpath) = path_expr.node {
+                let def_id = cx.tables.qpath_def(qpath, path_expr.id).def_id();
+                if match_def_path(cx.tcx, def_id, &paths::PROCESS_EXIT) {
+                    // the closest enclosing item, closures are not items
+                    let parent = cx.tcx.hir.get_parent(e.id);
+                    let in_main = cx.sess().entry_fn.borrow().map_or(false, |(main, _)| main == parent);
+                    if !in_main {
+                        span_lint(cx, EXIT, e.span, "usage of `process::exit` outside of `main`");
+                    }
+                }
+            }
+        }
+    }
+}
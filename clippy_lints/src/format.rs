@@ -1,20 +1,20 @@
 use rustc::hir::*;
 use rustc::hir::map::Node::NodeItem;
 use rustc::lint::*;
-use rustc::ty::TypeVariants;
 use syntax::ast::LitKind;
 use syntax::symbol::InternedString;
 use utils::paths;
-use utils::{is_expn_of, match_def_path, match_type, resolve_node, span_lint, walk_ptrs_ty};
+use utils::{is_expn_of, match_def_path, resolve_node, span_lint_and_then};
+use utils::sugg::Sugg;
 
 /// **What it does:** Checks for the use of `format!("string literal with no
-/// argument")` and `format!("{}", foo)` where `foo` is a string.
+/// argument")` and `format!("{}", foo)`.
 ///
-/// **Why is this bad?** There is no point of doing that. `format!("too")` can
-/// be replaced by `"foo".to_owned()` if you really need a `String`. The even
-/// worse `&format!("foo")` is often encountered in the wild. `format!("{}",
-/// foo)` can be replaced by `foo.clone()` if `foo: String` or `foo.to_owned()`
-/// if `foo: &str`.
+/// **Why is this bad?** There is no point of doing that. `format!("foo")` can
+/// be replaced by `"foo".to_owned()` if you really need a `String`, and
+/// `format!("")` by `String::new()`. The even worse `&format!("foo")` is often
+/// encountered in the wild. `format!("{}", foo)` can be replaced by
+/// `foo.to_string()`.
 ///
 /// **Known problems:** None.
 ///
@@ -40,33 +40,36 @@ impl LintPass for Pass {
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
-        if let Some(span) = is_expn_of(expr.span, "format") {
-            match expr.node {
-                // `format!("{}", foo)` expansion
-                ExprCall(ref fun, ref args) => {
-                    if_let_chain!{[
-                        let ExprPath(ref qpath) = fun.node,
-                        args.len() == 2,
-                        match_def_path(cx.tcx, resolve_node(cx, qpath, fun.id).def_id(), &paths::FMT_ARGUMENTS_NEWV1),
-                        // ensure the format string is `"{..}"` with only one argument and no text
-                        check_static_str(cx, &args[0]),
-                        // ensure the format argument is `{}` ie. Display with no fancy option
-                        check_arg_is_display(cx, &args[1])
-                    ], {
-                        span_lint(cx, USELESS_FORMAT, span, "useless use of `format!`");
-                    }}
-                },
-                // `format!("foo")` expansion contains `match () { () => [], }`
-                ExprMatch(ref matchee, _, _) => {
-                    if let ExprTup(ref tup) = matchee.node {
-                        if tup.is_empty() {
-                            span_lint(cx, USELESS_FORMAT, span, "useless use of `format!`");
-                        }
-                    }
-                },
-                _ => (),
-            }
-        }
+        if_let_chain!{[
+            let Some(span) = is_expn_of(expr.span, "format"),
+            let ExprCall(ref fun, ref args) = expr.node,
+            let ExprPath(ref qpath) = fun.node,
+            args.len() == 2,
+            match_def_path(cx.tcx, resolve_node(cx, qpath, fun.id).def_id(), &paths::FMT_ARGUMENTS_NEWV1),
+            let Some(parts) = get_argument_fmtstr_parts(cx, &args[0]),
+        ], {
+            let sugg = if let Some(arg) = get_display_arg(cx, &args[1]) {
+                // `format!("{}", foo)` expansion, the format string must be `"{}"` with no text
+                if parts.len() != 1 || !parts[0].is_empty() {
+                    return;
+                }
+                format!("{}.to_string()", Sugg::hir(cx, arg, "<arg>").maybe_par())
+            } else if has_no_args(&args[1]) {
+                // `format!("foo")` expansion
+                let lit = parts.iter().map(|part| &**part).collect::<String>();
+                if lit.is_empty() {
+                    "String::new()".to_owned()
+                } else {
+                    format!("{:?}.to_owned()", lit)
+                }
+            } else {
+                return;
+            };
+
+            span_lint_and_then(cx, USELESS_FORMAT, span, "useless use of `format!`", |db| {
+                db.span_suggestion(span, "consider using", sugg);
+            });
+        }}
     }
 }
 
@@ -97,28 +100,38 @@ pub fn get_argument_fmtstr_parts<'a, 'b>(cx: &LateContext<'a, 'b>, expr: &'a Exp
     None
 }
 
-/// Checks if the expressions matches
-/// ```rust, ignore
-/// { static __STATIC_FMTSTR: &'static[&'static str] = &["a", "b", c]; __STATIC_FMTSTR }
+/// Checks if the expression matches
+/// ```rust,ignore
+/// &match () {
+///     () => [],
+/// }
 /// ```
-fn check_static_str(cx: &LateContext, expr: &Expr) -> bool {
-    if let Some(expr) = get_argument_fmtstr_parts(cx, expr) {
-        expr.len() == 1 && expr[0].is_empty()
-    } else {
-        false
-    }
+fn has_no_args(expr: &Expr) -> bool {
+    if_let_chain! {[
+        let ExprAddrOf(_, ref expr) = expr.node,
+        let ExprMatch(ref matchee, _, _) = expr.node,
+        let ExprTup(ref tup) = matchee.node,
+    ], {
+        return tup.is_empty();
+    }}
+
+    false
 }
 
-/// Checks if the expressions matches
+/// Checks if the expression matches
 /// ```rust,ignore
 /// &match (&42,) {
 ///     (__arg0,) => [::std::fmt::ArgumentV1::new(__arg0, ::std::fmt::Display::fmt)],
 /// }
 /// ```
-fn check_arg_is_display(cx: &LateContext, expr: &Expr) -> bool {
+/// and returns the formatted argument (`42` here).
+fn get_display_arg<'e>(cx: &LateContext, expr: &'e Expr) -> Option<&'e Expr> {
     if_let_chain! {[
         let ExprAddrOf(_, ref expr) = expr.node,
-        let ExprMatch(_, ref arms, _) = expr.node,
+        let ExprMatch(ref matchee, ref arms, _) = expr.node,
+        let ExprTup(ref tup) = matchee.node,
+        tup.len() == 1,
+        let ExprAddrOf(_, ref arg) = tup[0].node,
         arms.len() == 1,
         arms[0].pats.len() == 1,
         let PatKind::Tuple(ref pat, None) = arms[0].pats[0].node,
@@ -130,10 +143,8 @@ fn check_arg_is_display(cx: &LateContext, expr: &Expr) -> bool {
         let ExprPath(ref qpath) = args[1].node,
         match_def_path(cx.tcx, resolve_node(cx, qpath, args[1].id).def_id(), &paths::DISPLAY_FMT_METHOD),
     ], {
-        let ty = walk_ptrs_ty(cx.tables.pat_ty(&pat[0]));
-
-        return ty.sty == TypeVariants::TyStr || match_type(cx, ty, &paths::STRING);
+        return Some(arg);
     }}
 
-    false
+    None
 }
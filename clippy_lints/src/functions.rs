@@ -6,7 +6,7 @@ use std::collections::HashSet;
 use syntax::ast;
 use syntax::abi::Abi;
 use syntax::codemap::Span;
-use utils::{span_lint, type_is_unsafe_function, iter_input_pats};
+use utils::{in_external_macro, span_lint, type_is_unsafe_function, iter_input_pats};
 
 /// **What it does:** Checks for functions with too many parameters.
 ///
@@ -51,20 +51,46 @@ declare_lint! {
     "public functions dereferencing raw pointer arguments but not marked `unsafe`"
 }
 
+/// **What it does:** Checks for functions whose body spans more lines than a
+/// configurable threshold.
+///
+/// **Why is this bad?** Long functions are hard to read and to reason about.
+/// Consider splitting them into smaller functions.
+///
+/// **Known problems:** Lines are counted in the source, so blank lines and
+/// comments count as well.
+///
+/// **Example:**
+/// ```rust
+/// fn im_too_long() {
+///     println!("");
+///     // ... 100 more lines
+/// }
+/// ```
+declare_lint! {
+    pub TOO_MANY_LINES,
+    Allow,
+    "functions with too many lines"
+}
+
 #[derive(Copy,Clone)]
 pub struct Functions {
     threshold: u64,
+    max_lines: u64,
 }
 
 impl Functions {
-    pub fn new(threshold: u64) -> Functions {
-        Functions { threshold: threshold }
+    pub fn new(threshold: u64, max_lines: u64) -> Functions {
+        Functions {
+            threshold: threshold,
+            max_lines: max_lines,
+        }
     }
 }
 
 impl LintPass for Functions {
     fn get_lints(&self) -> LintArray {
-        lint_array!(TOO_MANY_ARGUMENTS, NOT_UNSAFE_PTR_ARG_DEREF)
+        lint_array!(TOO_MANY_ARGUMENTS, NOT_UNSAFE_PTR_ARG_DEREF, TOO_MANY_LINES)
     }
 }
 
@@ -103,6 +129,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Functions {
         }
 
         self.check_raw_ptr(cx, unsafety, decl, body, nodeid);
+        self.check_line_number(cx, span, body);
     }
 
     fn check_trait_item(&mut self, cx: &LateContext<'a, 'tcx>, item: &'tcx hir::TraitItem) {
@@ -131,6 +158,25 @@ impl<'a, 'tcx> Functions {
         }
     }
 
+    fn check_line_number(&self, cx: &LateContext, span: Span, body: &hir::Body) {
+        if in_external_macro(cx, span) {
+            return;
+        }
+
+        // only count the lines between the braces of the body, not the signature
+        let codemap = cx.sess().codemap();
+        let lo = codemap.lookup_char_pos(body.value.span.lo).line as u64;
+        let hi = codemap.lookup_char_pos(body.value.span.hi).line as u64;
+        let lines = (hi - lo).saturating_sub(1);
+
+        if lines > self.max_lines {
+            span_lint(cx,
+                      TOO_MANY_LINES,
+                      span,
+                      &format!("this function has too many lines ({}/{})", lines, self.max_lines));
+        }
+    }
+
     fn check_raw_ptr(
         &self,
         cx: &LateContext<'a, 'tcx>,
@@ -0,0 +1,174 @@
+use rustc::hir::*;
+use rustc::hir::def::Def;
+use rustc::lint::*;
+use rustc::ty;
+use syntax::ast::Name;
+use utils::{higher, match_def_path, match_type, paths, snippet, span_help_and_lint, walk_ptrs_ty};
+use utils::loop_body::{walk_loop_body, LoopBodyVisitor, LoopTarget};
+
+/// **What it does:** Checks for `HashMap`s and `HashSet`s created with `new()`
+/// right before a `for` loop which inserts one element per item of a
+/// collection of known length.
+///
+/// **Why is this bad?** The number of elements is known in advance, creating
+/// the collection with `with_capacity` avoids rehashing it several times
+/// while it grows.
+///
+/// **Known problems:** The lint only fires when every iteration of the loop
+/// inserts exactly once, so that the length of the iterated collection is an
+/// upper bound of the number of elements.
+///
+/// **Example:**
+/// ```rust
+/// let mut map = HashMap::new();
+/// for x in &v {
+///     map.insert(x.key, x.value);
+/// }
+/// ```
+/// Could be written:
+/// ```rust
+/// let mut map = HashMap::with_capacity(v.len());
+/// for x in &v {
+///     map.insert(x.key, x.value);
+/// }
+/// ```
+declare_lint! {
+    pub HASH_WITHOUT_CAPACITY,
+    Warn,
+    "creating a `HashMap` or `HashSet` with `new()` before filling it from a collection of known length"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(HASH_WITHOUT_CAPACITY)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for window in block.stmts.windows(2) {
+            if_let_chain! {[
+                let StmtDecl(ref decl, _) = window[0].node,
+                let DeclLocal(ref local) = decl.node,
+                let PatKind::Binding(BindByValue(MutMutable), _, ref name, None) = local.pat.node,
+                let Some(ref init) = local.init,
+                let Some(kind) = hash_new_call(cx, init),
+                let StmtExpr(ref loop_, _) = window[1].node,
+                let Some((_, arg, body)) = higher::for_loop(loop_),
+                let Some(collection) = sized_collection(cx, arg),
+                let ExprBlock(ref body) = body.node,
+                inserts_once_into(body, name.node),
+            ], {
+                span_help_and_lint(cx,
+                                   HASH_WITHOUT_CAPACITY,
+                                   init.span,
+                                   &format!("this `{}` is filled with one element per item of `{}`",
+                                            kind,
+                                            snippet(cx, collection.span, "..")),
+                                   &format!("consider using `{}::with_capacity({}.len())`",
+                                            kind,
+                                            snippet(cx, collection.span, "..")));
+            }}
+        }
+    }
+}
+
+/// If `expr` is a call to `HashMap::new()` or `HashSet::new()`, return the name of the type.
+fn hash_new_call(cx: &LateContext, expr: &Expr) -> Option<&'static str> {
+    if_let_chain! {[
+        let ExprCall(ref fun, ref args) = expr.node,
+        args.is_empty(),
+        let ExprPath(ref path) = fun.node,
+        let Def::Method(did) = cx.tables.qpath_def(path, fun.id),
+    ], {
+        if match_def_path(cx.tcx, did, &paths::HASHMAP_NEW) {
+            return Some("HashMap");
+        } else if match_def_path(cx.tcx, did, &paths::HASHSET_NEW) {
+            return Some("HashSet");
+        }
+    }}
+    None
+}
+
+/// If the `for` loop argument `arg` iterates over a collection whose length is
+/// known (`v`, `&v`, `&mut v`, `v.iter()`, `v.iter_mut()` or `v.into_iter()` where `v` is
+/// a `Vec`, a `VecDeque`, an array or a slice), return that collection.
+fn sized_collection<'e>(cx: &LateContext, arg: &'e Expr) -> Option<&'e Expr> {
+    let collection = match arg.node {
+        ExprAddrOf(_, ref inner) => &**inner,
+        ExprMethodCall(ref name, _, ref args) if args.len() == 1 &&
+                                                  ["iter", "iter_mut", "into_iter"].contains(&&*name.node.as_str()) => {
+            &args[0]
+        },
+        _ => arg,
+    };
+
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(collection));
+    let is_sized = match ty.sty {
+        ty::TyArray(..) | ty::TySlice(..) => true,
+        _ => match_type(cx, ty, &paths::VEC) || match_type(cx, ty, &paths::VEC_DEQUE),
+    };
+
+    if is_sized {
+        Some(collection)
+    } else {
+        None
+    }
+}
+
+/// Return true if the loop body `body` unconditionally calls `name.insert(..)` as one of
+/// its statements, and cannot skip it with `break` or `continue`.
+fn inserts_once_into(body: &Block, name: Name) -> bool {
+    let inserts = body.stmts
+        .iter()
+        .filter(|stmt| match stmt.node {
+            StmtExpr(ref expr, _) |
+            StmtSemi(ref expr, _) => is_insert_into(expr, name),
+            StmtDecl(..) => false,
+        })
+        .count() + body.expr.as_ref().map_or(0, |expr| is_insert_into(expr, name) as usize);
+    if inserts != 1 {
+        return false;
+    }
+
+    let mut visitor = EarlyExitVisitor { found: false };
+    walk_loop_body(body, None, &mut visitor);
+    !visitor.found
+}
+
+/// Is `expr` a call to `name.insert(..)`?
+fn is_insert_into(expr: &Expr, name: Name) -> bool {
+    if_let_chain! {[
+        let ExprMethodCall(ref method, _, ref args) = expr.node,
+        method.node == "insert",
+        let ExprPath(QPath::Resolved(None, ref path)) = args[0].node,
+        path.segments.len() == 1,
+    ], {
+        return path.segments[0].name == name;
+    }}
+    false
+}
+
+/// Looks for a `break` or `continue` which could skip the insertion.
+struct EarlyExitVisitor {
+    found: bool,
+}
+
+impl<'tcx> LoopBodyVisitor<'tcx> for EarlyExitVisitor {
+    fn visit_break(&mut self, _expr: &'tcx Expr, target: LoopTarget) {
+        if let LoopTarget::Inner(_) = target {
+            return;
+        }
+        self.found = true;
+    }
+
+    fn visit_continue(&mut self, _expr: &'tcx Expr, target: LoopTarget) {
+        if let LoopTarget::Inner(_) = target {
+            return;
+        }
+        self.found = true;
+    }
+}
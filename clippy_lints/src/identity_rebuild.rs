@@ -0,0 +1,67 @@
+use rustc::lint::{LateContext, LateLintPass, LintArray, LintPass};
+use rustc::hir::{Expr, ExprAssign, ExprMethodCall};
+use utils::{in_macro, method_chain_args, snippet, span_help_and_lint, SpanlessEq};
+
+/// **What it does:** Checks for assignments that rebuild a collection from
+/// itself, like `v = v.clone()` or `v = v.iter().cloned().collect()`.
+///
+/// **Why is this bad?** The assigned value is identical to the one it
+/// replaces, so the operation only costs an allocation and a copy. It is
+/// also often a sign that a copy was intended to go somewhere else.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// v = v.iter().cloned().collect();
+/// ```
+declare_lint! {
+    pub IDENTITY_REBUILD,
+    Warn,
+    "assigning a clone or identical rebuild of a value to itself"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(IDENTITY_REBUILD)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprAssign(ref target, ref value) = expr.node {
+            if let Some(source) = rebuilt_from(value) {
+                if SpanlessEq::new(cx).ignore_fn().eq_expr(target, source) {
+                    span_help_and_lint(cx,
+                                       IDENTITY_REBUILD,
+                                       expr.span,
+                                       &format!("this assigns an identical copy of `{}` to itself",
+                                                snippet(cx, target.span, "..")),
+                                       "this is a no-op and can be removed; if a copy was intended, bind the \
+                                        result of `clone()` to another variable");
+                }
+            }
+        }
+    }
+}
+
+/// If `expr` rebuilds a value unchanged (`x.clone()`, `x.iter().cloned().collect()` or
+/// `x.into_iter().collect()`), return the expression it is rebuilt from.
+fn rebuilt_from(expr: &Expr) -> Option<&Expr> {
+    if let Some(args) = method_chain_args(expr, &["iter", "cloned", "collect"]) {
+        return Some(&args[0][0]);
+    }
+    if let Some(args) = method_chain_args(expr, &["into_iter", "collect"]) {
+        return Some(&args[0][0]);
+    }
+    match expr.node {
+        ExprMethodCall(ref name, _, ref args) if name.node == "clone" && args.len() == 1 => Some(&args[0]),
+        _ => None,
+    }
+}
@@ -1,7 +1,9 @@
 use rustc::lint::*;
 use rustc::hir::*;
+use syntax::ast::LitKind;
 use syntax::codemap::Span;
-use utils::{paths, span_lint_and_then, match_path, snippet};
+use utils::{paths, span_lint_and_then, match_path, remove_blocks};
+use utils::sugg::Sugg;
 
 /// **What it does:*** Lint for redundant pattern matching over `Result` or `Option`
 ///
@@ -33,12 +35,39 @@ declare_lint! {
     "use the proper utility function avoiding an `if let`"
 }
 
+/// **What it does:** Lint for an `if let` over `Option` or `Result` whose only
+/// purpose is to produce a boolean.
+///
+/// **Why is this bad?** It's more concise and clear to just call the proper
+/// utility function.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+///
+/// ```rust
+/// let present = if let Some(_) = x { true } else { false };
+/// let failed = if let Ok(_) = y { false } else { true };
+/// ```
+///
+/// The more idiomatic use would be:
+///
+/// ```rust
+/// let present = x.is_some();
+/// let failed = y.is_err();
+/// ```
+declare_lint! {
+    pub REDUNDANT_IF_LET_SOME,
+    Warn,
+    "`if let Some(_) = x { true } else { false }`, which can be written as `x.is_some()`"
+}
+
 #[derive(Copy, Clone)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(IF_LET_REDUNDANT_PATTERN_MATCHING)
+        lint_array!(IF_LET_REDUNDANT_PATTERN_MATCHING, REDUNDANT_IF_LET_SOME)
     }
 }
 
@@ -49,24 +78,44 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
 
             if arms[0].pats.len() == 1 {
 
-                let good_method = match arms[0].pats[0].node {
+                // (method, method for the negated test)
+                let (good_method, inverse_method) = match arms[0].pats[0].node {
                     PatKind::TupleStruct(ref path, ref pats, _) if pats.len() == 1 && pats[0].node == PatKind::Wild => {
                         if match_path(path, &paths::RESULT_OK) {
-                            "is_ok()"
+                            ("is_ok()", "is_err()")
                         } else if match_path(path, &paths::RESULT_ERR) {
-                            "is_err()"
+                            ("is_err()", "is_ok()")
                         } else if match_path(path, &paths::OPTION_SOME) {
-                            "is_some()"
+                            ("is_some()", "is_none()")
                         } else {
                             return;
                         }
                     },
 
-                    PatKind::Path(ref path) if match_path(path, &paths::OPTION_NONE) => "is_none()",
+                    PatKind::Path(ref path) if match_path(path, &paths::OPTION_NONE) => ("is_none()", "is_some()"),
 
                     _ => return,
                 };
 
+                if arms.len() == 2 {
+                    if let (Some(then_value), Some(else_value)) = (bool_lit(&arms[0].body), bool_lit(&arms[1].body)) {
+                        if then_value != else_value {
+                            let method = if then_value { good_method } else { inverse_method };
+                            span_lint_and_then(cx,
+                                               REDUNDANT_IF_LET_SOME,
+                                               expr.span,
+                                               &format!("this `if let` only produces a boolean, consider using `{}`",
+                                                        method),
+                                               |db| {
+                                db.span_suggestion(expr.span,
+                                                   "try this",
+                                                   format!("{}.{}", Sugg::hir(cx, op, "_").maybe_par(), method));
+                            });
+                            return;
+                        }
+                    }
+                }
+
                 span_lint_and_then(cx,
                                    IF_LET_REDUNDANT_PATTERN_MATCHING,
                                    arms[0].pats[0].span,
@@ -77,10 +126,22 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                         hi: op.span.hi,
                         ctxt: expr.span.ctxt,
                     };
-                    db.span_suggestion(span, "try this", format!("if {}.{}", snippet(cx, op.span, "_"), good_method));
+                    db.span_suggestion(span,
+                                       "try this",
+                                       format!("if {}.{}", Sugg::hir(cx, op, "_").maybe_par(), good_method));
                 });
             }
 
         }
     }
 }
+
+/// Returns the value of a boolean literal, looking through blocks like `{ true }`.
+fn bool_lit(expr: &Expr) -> Option<bool> {
+    if let ExprLit(ref lit) = remove_blocks(expr).node {
+        if let LitKind::Bool(value) = lit.node {
+            return Some(value);
+        }
+    }
+    None
+}
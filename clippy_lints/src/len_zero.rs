@@ -2,12 +2,14 @@ use rustc::lint::*;
 use rustc::hir::def_id::DefId;
 use rustc::ty;
 use rustc::hir::*;
-use syntax::ast::{Lit, LitKind, Name};
+use syntax::ast::{Lit, LitKind};
 use syntax::codemap::{Span, Spanned};
-use utils::{get_item_name, in_macro, snippet, span_lint, span_lint_and_then, walk_ptrs_ty};
+use utils::comparisons::{normalize_comparison, Rel};
+use utils::{get_item_name, in_macro, match_trait_method, paths, snippet, span_help_and_lint, span_lint,
+            span_lint_and_then, walk_ptrs_ty};
 
 /// **What it does:** Checks for getting the length of something via `.len()`
-/// just to compare to zero, and suggests using `.is_empty()` where applicable.
+/// just to compare to zero (or `.len() >= 1`), and suggests using `.is_empty()` where applicable.
 ///
 /// **Why is this bad?** Some structures can answer `.is_empty()` much faster
 /// than calculating their length. So it is good to get into the habit of using
@@ -50,12 +52,33 @@ declare_lint! {
     "traits or impls with a public `len` method but no corresponding `is_empty` method"
 }
 
+/// **What it does:** Checks for comparing the `.len()` of an
+/// `ExactSizeIterator` to zero.
+///
+/// **Why is this bad?** Iterators have no stable `is_empty` method, and
+/// whether an iterator yields anything at all is more directly expressed by
+/// asking for the next item.
+///
+/// **Known problems:** `.next()` advances the iterator, so it needs to be
+/// mutable, and the replacement is only equivalent if the iterator is not used
+/// afterwards.
+///
+/// **Example:**
+/// ```rust
+/// if v.iter().map(|x| x + 1).len() > 0 { .. }
+/// ```
+declare_lint! {
+    pub ITER_LEN_ZERO,
+    Allow,
+    "checking `.len() == 0` or `.len() > 0` (or similar) on an `ExactSizeIterator`"
+}
+
 #[derive(Copy,Clone)]
 pub struct LenZero;
 
 impl LintPass for LenZero {
     fn get_lints(&self) -> LintArray {
-        lint_array!(LEN_ZERO, LEN_WITHOUT_IS_EMPTY)
+        lint_array!(LEN_ZERO, LEN_WITHOUT_IS_EMPTY, ITER_LEN_ZERO)
     }
 }
 
@@ -79,8 +102,16 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LenZero {
 
         if let ExprBinary(Spanned { node: cmp, .. }, ref left, ref right) = expr.node {
             match cmp {
-                BiEq => check_cmp(cx, expr.span, left, right, ""),
-                BiGt | BiNe => check_cmp(cx, expr.span, left, right, "!"),
+                BiEq => check_cmp(cx, expr.span, left, right, "", 0),
+                BiGt | BiNe => check_cmp(cx, expr.span, left, right, "!", 0),
+                BiGe | BiLe => {
+                    // `x.len() >= 1` or `1 <= x.len()`
+                    if let Some((Rel::Le, lit, len)) = normalize_comparison(cmp, left, right) {
+                        if let ExprLit(_) = lit.node {
+                            check_cmp(cx, expr.span, lit, len, "!", 1);
+                        }
+                    }
+                },
                 _ => (),
             }
         }
@@ -152,30 +183,41 @@ fn check_impl_items(cx: &LateContext, item: &Item, impl_items: &[ImplItemRef]) {
     }
 }
 
-fn check_cmp(cx: &LateContext, span: Span, left: &Expr, right: &Expr, op: &str) {
+fn check_cmp(cx: &LateContext, span: Span, left: &Expr, right: &Expr, op: &str, compare_to: u128) {
     // check if we are in an is_empty() method
     if let Some(name) = get_item_name(cx, left) {
         if name == "is_empty" {
             return;
         }
     }
-    match (&left.node, &right.node) {
-        (&ExprLit(ref lit), &ExprMethodCall(ref method, _, ref args)) |
-        (&ExprMethodCall(ref method, _, ref args), &ExprLit(ref lit)) => {
-            check_len_zero(cx, span, method.node, args, lit, op)
-        },
-        _ => (),
-    }
+    let (lit, call) = match (&left.node, &right.node) {
+        (&ExprLit(ref lit), &ExprMethodCall(..)) => (lit, right),
+        (&ExprMethodCall(..), &ExprLit(ref lit)) => (lit, left),
+        _ => return,
+    };
+    check_len_zero(cx, span, call, lit, op, compare_to)
 }
 
-fn check_len_zero(cx: &LateContext, span: Span, name: Name, args: &[Expr], lit: &Lit, op: &str) {
-    if let Spanned { node: LitKind::Int(0, _), .. } = *lit {
-        if name == "len" && args.len() == 1 && has_is_empty(cx, &args[0]) {
-            span_lint_and_then(cx, LEN_ZERO, span, "length comparison to zero", |db| {
+fn check_len_zero(cx: &LateContext, span: Span, call: &Expr, lit: &Lit, op: &str, compare_to: u128) {
+    if let (&ExprMethodCall(ref name, _, ref args), &Spanned { node: LitKind::Int(value, _), .. }) = (&call.node, lit) {
+        if value != compare_to || name.node != "len" || args.len() != 1 {
+            return;
+        }
+        let number = if compare_to == 0 { "zero" } else { "one" };
+        if has_is_empty(cx, &args[0]) {
+            span_lint_and_then(cx, LEN_ZERO, span, &format!("length comparison to {}", number), |db| {
                 db.span_suggestion(span,
                                    "consider using `is_empty`",
                                    format!("{}{}.is_empty()", op, snippet(cx, args[0].span, "_")));
             });
+        } else if match_trait_method(cx, call, &paths::EXACT_SIZE_ITERATOR) {
+            span_help_and_lint(cx,
+                               ITER_LEN_ZERO,
+                               span,
+                               &format!("length comparison to {} on an iterator", number),
+                               &format!("if the iterator is not used afterwards, consider using `{}.next().{}()`",
+                                        snippet(cx, args[0].span, "_"),
+                                        if op.is_empty() { "is_none" } else { "is_some" }));
         }
     }
 }
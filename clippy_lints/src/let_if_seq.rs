@@ -1,7 +1,7 @@
 use rustc::lint::*;
 use rustc::hir;
 use syntax_pos::{Span, NO_EXPANSION};
-use utils::{snippet, span_lint_and_then};
+use utils::{snippet, span_lint_and_then, used_in_expr, UsedVisitor};
 
 /// **What it does:** Checks for variable declarations immediately followed by a
 /// conditional affectation.
@@ -128,28 +128,6 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for LetIfSeq {
     }
 }
 
-struct UsedVisitor<'a, 'tcx: 'a> {
-    cx: &'a LateContext<'a, 'tcx>,
-    id: hir::def_id::DefId,
-    used: bool,
-}
-
-impl<'a, 'tcx> hir::intravisit::Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
-    fn visit_expr(&mut self, expr: &'tcx hir::Expr) {
-        if_let_chain! {[
-            let hir::ExprPath(ref qpath) = expr.node,
-            self.id == self.cx.tables.qpath_def(qpath, expr.id).def_id(),
-        ], {
-            self.used = true;
-            return;
-        }}
-        hir::intravisit::walk_expr(self, expr);
-    }
-    fn nested_visit_map<'this>(&'this mut self) -> hir::intravisit::NestedVisitorMap<'this, 'tcx> {
-        hir::intravisit::NestedVisitorMap::All(&self.cx.tcx.hir)
-    }
-}
-
 fn check_assign<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
     decl: hir::def_id::DefId,
@@ -182,13 +160,3 @@ fn check_assign<'a, 'tcx>(
 
     None
 }
-
-fn used_in_expr<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, id: hir::def_id::DefId, expr: &'tcx hir::Expr) -> bool {
-    let mut v = UsedVisitor {
-        cx: cx,
-        id: id,
-        used: false,
-    };
-    hir::intravisit::walk_expr(&mut v, expr);
-    v.used
-}
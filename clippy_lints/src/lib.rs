@@ -72,6 +72,7 @@ pub mod doc;
 pub mod double_parens;
 pub mod drop_forget_ref;
 pub mod empty_enum;
+pub mod empty_if_branch;
 pub mod entry;
 pub mod enum_clike;
 pub mod enum_glob_use;
@@ -80,10 +81,13 @@ pub mod eq_op;
 pub mod escape;
 pub mod eta_reduction;
 pub mod eval_order_dependence;
+pub mod exit;
 pub mod format;
 pub mod formatting;
 pub mod functions;
+pub mod hash_capacity;
 pub mod identity_op;
+pub mod identity_rebuild;
 pub mod if_let_redundant_pattern_matching;
 pub mod if_not_else;
 pub mod items_after_statements;
@@ -91,6 +95,7 @@ pub mod large_enum_variant;
 pub mod len_zero;
 pub mod let_if_seq;
 pub mod lifetimes;
+pub mod literal_representation;
 pub mod loops;
 pub mod map_clone;
 pub mod matches;
@@ -105,8 +110,8 @@ pub mod mut_reference;
 pub mod mutex_atomic;
 pub mod needless_bool;
 pub mod needless_borrow;
-pub mod needless_pass_by_value;
 pub mod needless_continue;
+pub mod needless_pass_by_value;
 pub mod needless_update;
 pub mod neg_multiply;
 pub mod new_without_default;
@@ -127,12 +132,14 @@ pub mod returns;
 pub mod serde;
 pub mod shadow;
 pub mod should_assert_eq;
+pub mod sort_after_collect;
 pub mod strings;
 pub mod swap;
 pub mod temporary_assignment;
 pub mod transmute;
 pub mod types;
 pub mod unicode;
+pub mod unnecessary_unwrap;
 pub mod unsafe_removed_from_name;
 pub mod unused_io_amount;
 pub mod unused_label;
@@ -144,9 +151,10 @@ mod reexport {
     pub use syntax::ast::{Name, NodeId};
 }
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
-pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
-    let conf = match utils::conf::file_from_args(reg.args()) {
+/// Read Clippy's configuration, either from the file given by the `conf_file` plugin argument or
+/// from the first `clippy.toml` found. Errors are reported and the default configuration is used.
+pub fn read_conf(reg: &rustc_plugin::Registry) -> utils::conf::Conf {
+    match utils::conf::file_from_args(reg.args()) {
         Ok(file_name) => {
             // if the user specified a file, it must exist, otherwise default to `clippy.toml` but
             // do not require the file to exist
@@ -177,8 +185,18 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
                     .emit();
             utils::conf::Conf::default()
         }
-    };
+    }
+}
+
+/// Register Clippy's lints using the configuration read by `read_conf`.
+pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
+    let conf = read_conf(reg);
+    register_plugins_with_conf(reg, &conf);
+}
 
+/// Register Clippy's lints using the given configuration.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+pub fn register_plugins_with_conf(reg: &mut rustc_plugin::Registry, conf: &utils::conf::Conf) {
     let mut store = reg.sess.lint_store.borrow_mut();
     store.register_removed(
         "extend_from_slice",
@@ -236,13 +254,14 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box shadow::Pass);
     reg.register_late_lint_pass(box types::LetPass);
     reg.register_late_lint_pass(box types::UnitCmp);
-    reg.register_late_lint_pass(box loops::Pass);
+    reg.register_late_lint_pass(box loops::Pass::new(conf.explicit_counter_max_uses));
     reg.register_late_lint_pass(box lifetimes::LifetimePass);
     reg.register_late_lint_pass(box entry::HashMapLint);
     reg.register_late_lint_pass(box ranges::StepByZero);
     reg.register_late_lint_pass(box types::CastPass);
     reg.register_late_lint_pass(box types::TypeComplexityPass::new(conf.type_complexity_threshold));
     reg.register_late_lint_pass(box matches::MatchPass);
+    reg.register_late_lint_pass(box matches::UnitArmStyle::new(conf.unit_arm_style.clone()));
     reg.register_late_lint_pass(box minmax::MinMaxPass);
     reg.register_late_lint_pass(box open_options::NonSensical);
     reg.register_late_lint_pass(box zero_div_zero::Pass);
@@ -281,9 +300,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box overflow_check_conditional::OverflowCheckConditional);
     reg.register_late_lint_pass(box unused_label::UnusedLabel);
     reg.register_late_lint_pass(box new_without_default::NewWithoutDefault);
-    reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(conf.blacklisted_names));
-    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold));
-    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents));
+    reg.register_late_lint_pass(box blacklisted_name::BlackListedName::new(conf.blacklisted_names.clone()));
+    reg.register_late_lint_pass(box functions::Functions::new(conf.too_many_arguments_threshold,
+                                                                conf.too_many_lines_threshold));
+    reg.register_early_lint_pass(box doc::Doc::new(conf.doc_valid_idents.clone()));
     reg.register_late_lint_pass(box neg_multiply::NegMultiply);
     reg.register_early_lint_pass(box unsafe_removed_from_name::UnsafeNameRemoval);
     reg.register_late_lint_pass(box mem_forget::MemForget);
@@ -301,24 +321,49 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
     reg.register_late_lint_pass(box large_enum_variant::LargeEnumVariant::new(conf.enum_variant_size_threshold));
     reg.register_late_lint_pass(box should_assert_eq::ShouldAssertEq);
     reg.register_late_lint_pass(box needless_pass_by_value::NeedlessPassByValue);
+    reg.register_late_lint_pass(box unnecessary_unwrap::Pass);
+    reg.register_late_lint_pass(box strings::ManualStrip);
+    reg.register_late_lint_pass(box exit::Pass);
+    reg.register_late_lint_pass(box identity_rebuild::Pass);
+    reg.register_late_lint_pass(box hash_capacity::Pass);
+    reg.register_early_lint_pass(
+        box literal_representation::LiteralDigitGrouping::new(conf.unreadable_literal_threshold)
+    );
+    reg.register_early_lint_pass(box empty_if_branch::EmptyIfBranch);
+    reg.register_late_lint_pass(box sort_after_collect::Pass);
 
     reg.register_lint_group("clippy_restrictions", vec![
         arithmetic::FLOAT_ARITHMETIC,
         arithmetic::INTEGER_ARITHMETIC,
         array_indexing::INDEXING_SLICING,
         assign_ops::ASSIGN_OPS,
+        exit::EXIT,
     ]);
 
     reg.register_lint_group("clippy_pedantic", vec![
+        attrs::DEPRECATED_SEMVER_PLACEHOLDER,
+        attrs::SHOULD_PANIC_WITHOUT_EXPECTED,
         booleans::NONMINIMAL_BOOL,
         empty_enum::EMPTY_ENUM,
+        empty_if_branch::EMPTY_IF_BRANCH,
         enum_glob_use::ENUM_GLOB_USE,
         enum_variants::PUB_ENUM_VARIANT_NAMES,
         enum_variants::STUTTER,
+        functions::TOO_MANY_LINES,
         if_not_else::IF_NOT_ELSE,
         items_after_statements::ITEMS_AFTER_STATEMENTS,
+        len_zero::ITER_LEN_ZERO,
+        literal_representation::UNREADABLE_LITERAL,
+        loops::HASH_COLLECT_LOOP,
+        loops::PUSH_WITHOUT_RESERVE,
+        loops::RANGE_REPEAT_LOOP,
+        loops::STRING_ADD_IN_LOOP,
         matches::SINGLE_MATCH_ELSE,
+        matches::UNIT_ARM_STYLE,
+        matches::UNREACHABLE_MATCH_ARM,
         mem_forget::MEM_FORGET,
+        methods::CHAINED_AND_THEN,
+        methods::CHARS_COUNT,
         methods::FILTER_MAP,
         methods::OPTION_MAP_UNWRAP_OR,
         methods::OPTION_MAP_UNWRAP_OR_ELSE,
@@ -333,9 +378,11 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         non_expressive_names::SIMILAR_NAMES,
         print::PRINT_STDOUT,
         print::USE_DEBUG,
+        ranges::MANUAL_RANGE_CONTAINS,
         shadow::SHADOW_REUSE,
         shadow::SHADOW_SAME,
         shadow::SHADOW_UNRELATED,
+        sort_after_collect::SORT_AFTER_COLLECT,
         strings::STRING_ADD,
         strings::STRING_ADD_ASSIGN,
         types::CAST_POSSIBLE_TRUNCATION,
@@ -395,14 +442,18 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         formatting::SUSPICIOUS_ELSE_FORMATTING,
         functions::NOT_UNSAFE_PTR_ARG_DEREF,
         functions::TOO_MANY_ARGUMENTS,
+        hash_capacity::HASH_WITHOUT_CAPACITY,
         identity_op::IDENTITY_OP,
+        identity_rebuild::IDENTITY_REBUILD,
         if_let_redundant_pattern_matching::IF_LET_REDUNDANT_PATTERN_MATCHING,
+        if_let_redundant_pattern_matching::REDUNDANT_IF_LET_SOME,
         large_enum_variant::LARGE_ENUM_VARIANT,
         len_zero::LEN_WITHOUT_IS_EMPTY,
         len_zero::LEN_ZERO,
         let_if_seq::USELESS_LET_IF_SEQ,
         lifetimes::NEEDLESS_LIFETIMES,
         lifetimes::UNUSED_LIFETIMES,
+        literal_representation::INCONSISTENT_DIGIT_GROUPING,
         loops::EMPTY_LOOP,
         loops::EXPLICIT_COUNTER_LOOP,
         loops::EXPLICIT_INTO_ITER_LOOP,
@@ -411,29 +462,47 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         loops::FOR_LOOP_OVER_OPTION,
         loops::FOR_LOOP_OVER_RESULT,
         loops::ITER_NEXT_LOOP,
+        loops::MANUAL_COUNT,
+        loops::MANUAL_FLATTEN,
         loops::NEEDLESS_RANGE_LOOP,
         loops::NEVER_LOOP,
         loops::REVERSE_RANGE_LOOP,
+        loops::SAME_ITEM_PUSH,
         loops::UNUSED_COLLECT,
+        loops::UNUSED_ENUMERATE_INDEX,
         loops::WHILE_LET_LOOP,
         loops::WHILE_LET_ON_ITERATOR,
+        loops::WHILE_LET_ON_NEW_ITERATOR,
         map_clone::MAP_CLONE,
+        matches::INFALLIBLE_DESTRUCTURING_MATCH,
+        matches::LITERAL_GUARD,
+        matches::MANUAL_MAP,
         matches::MATCH_BOOL,
         matches::MATCH_OVERLAPPING_ARM,
+        matches::MATCH_QUESTION_MARK,
         matches::MATCH_REF_PATS,
         matches::MATCH_WILD_ERR_ARM,
+        matches::REDUNDANT_PATTERN_BINDING,
         matches::SINGLE_MATCH,
         methods::CHARS_NEXT_CMP,
         methods::CLONE_DOUBLE_REF,
         methods::CLONE_ON_COPY,
+        methods::COLLECT_LEN,
         methods::FILTER_NEXT,
+        methods::GET_FIRST_LAST,
         methods::GET_UNWRAP,
+        methods::INTO_ITER_ON_ARRAY,
         methods::ITER_CLONED_COLLECT,
         methods::ITER_NTH,
         methods::ITER_SKIP_NEXT,
+        methods::NEEDLESS_AS_STR,
+        methods::NEEDLESS_OPTION_OR,
         methods::NEW_RET_NO_SELF,
         methods::OK_EXPECT,
+        methods::OPTION_AND_THEN_SOME,
         methods::OR_FUN_CALL,
+        methods::OR_INSERT_WITH_DEFAULT,
+        methods::RESULT_OR_ELSE_OK,
         methods::SEARCH_IS_SOME,
         methods::SHOULD_IMPLEMENT_TRAIT,
         methods::SINGLE_CHAR_PATTERN,
@@ -460,9 +529,10 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         mutex_atomic::MUTEX_ATOMIC,
         needless_bool::BOOL_COMPARISON,
         needless_bool::NEEDLESS_BOOL,
+        needless_borrow::BORROW_DEREF_REF,
         needless_borrow::NEEDLESS_BORROW,
-        needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         needless_continue::NEEDLESS_CONTINUE,
+        needless_pass_by_value::NEEDLESS_PASS_BY_VALUE,
         needless_update::NEEDLESS_UPDATE,
         neg_multiply::NEG_MULTIPLY,
         new_without_default::NEW_WITHOUT_DEFAULT,
@@ -490,6 +560,7 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         returns::NEEDLESS_RETURN,
         serde::SERDE_API_MISUSE,
         should_assert_eq::SHOULD_ASSERT_EQ,
+        strings::MANUAL_STRIP,
         strings::STRING_LIT_AS_BYTES,
         swap::ALMOST_SWAPPED,
         swap::MANUAL_SWAP,
@@ -503,16 +574,57 @@ pub fn register_plugins(reg: &mut rustc_plugin::Registry) {
         types::CHAR_LIT_AS_U8,
         types::LET_UNIT_VALUE,
         types::LINKEDLIST,
+        types::REDUNDANT_ALLOCATION,
         types::TYPE_COMPLEXITY,
         types::UNIT_CMP,
         types::UNNECESSARY_CAST,
         unicode::ZERO_WIDTH_SPACE,
+        unnecessary_unwrap::UNNECESSARY_UNWRAP,
         unsafe_removed_from_name::UNSAFE_REMOVED_FROM_NAME,
         unused_io_amount::UNUSED_IO_AMOUNT,
         unused_label::UNUSED_LABEL,
+        vec::EXTEND_WITH_VEC_MACRO,
         vec::USELESS_VEC,
+        vec::VEC_INIT_THEN_PUSH,
+        vec::VEC_MACRO_LEN,
         zero_div_zero::ZERO_DIVIDED_BY_ZERO,
     ]);
+
+    // the levels of the lints can only be set once they are in the lint store, so register them
+    // right away rather than leaving it to the driver
+    for pass in reg.early_lint_passes.drain(..) {
+        store.register_early_pass(Some(reg.sess), true, pass);
+    }
+    for pass in reg.late_lint_passes.drain(..) {
+        store.register_late_pass(Some(reg.sess), true, pass);
+    }
+    for (name, to) in reg.lint_groups.drain() {
+        store.register_group(Some(reg.sess), true, name, to);
+    }
+    set_denied_lints(reg.sess, &mut store, &conf.deny_lints);
+}
+
+/// Make the lints and lint groups of the `deny-lints` option errors by default. Lint attributes and
+/// command line flags still take precedence. Unknown names are reported and ignored.
+fn set_denied_lints(sess: &rustc::session::Session, store: &mut rustc::lint::LintStore, names: &[String]) {
+    for name in names {
+        let lints = if let Ok(lint) = store.find_lint(name, sess, None) {
+            vec![lint]
+        } else if let Some((_, lints, _)) = store.get_lint_groups()
+                                                 .into_iter()
+                                                 .find(|&(group, _, _)| group == name.as_str()) {
+            lints
+        } else {
+            sess.struct_err(&format!("error reading Clippy's configuration file: unknown lint or lint group `{}` in \
+                                      `deny-lints`",
+                                     name))
+                .emit();
+            continue;
+        };
+        for lint in lints {
+            store.set_level(lint, (rustc::lint::Level::Deny, rustc::lint::LintSource::Default));
+        }
+    }
 }
 
 // only exists to let the dogfood integration test works.
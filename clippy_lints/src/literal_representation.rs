@@ -0,0 +1,210 @@
+//! Lints concerned with the grouping of digits in numeric literals.
+
+use rustc::lint::*;
+use syntax::ast::*;
+use utils::{in_macro, snippet_opt, span_lint_and_sugg};
+
+/// **What it does:** Checks for long integer or float literals without `_`
+/// separators between groups of digits.
+///
+/// **Why is this bad?** Long runs of digits are hard to read, it is easy to
+/// miss a zero in `10000000`.
+///
+/// **Known problems:** None.
+///
+/// **Configuration:** The maximum number of digits a literal may have without
+/// separators is set with the `unreadable-literal-threshold` option.
+///
+/// **Example:**
+/// ```rust
+/// let x = 10000000;
+/// let y = 0xDEADBEEF;
+/// ```
+/// could be written as
+/// ```rust
+/// let x = 10_000_000;
+/// let y = 0xDEAD_BEEF;
+/// ```
+declare_lint! {
+    pub UNREADABLE_LITERAL,
+    Allow,
+    "long numeric literals without `_` separators between groups of digits"
+}
+
+/// **What it does:** Checks for numeric literals whose `_` separators split
+/// the digits in groups of varying sizes, like `1_00_000`.
+///
+/// **Why is this bad?** Uneven groups are misleading, `1_00_000` is easily
+/// read as a million.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let x = 1_00_000;
+/// ```
+/// could be written as
+/// ```rust
+/// let x = 100_000;
+/// ```
+declare_lint! {
+    pub INCONSISTENT_DIGIT_GROUPING,
+    Warn,
+    "numeric literals whose digits are separated in groups of varying sizes"
+}
+
+#[derive(Copy, Clone)]
+pub struct LiteralDigitGrouping {
+    threshold: u64,
+}
+
+impl LiteralDigitGrouping {
+    pub fn new(threshold: u64) -> Self {
+        LiteralDigitGrouping { threshold: threshold }
+    }
+}
+
+impl LintPass for LiteralDigitGrouping {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNREADABLE_LITERAL, INCONSISTENT_DIGIT_GROUPING)
+    }
+}
+
+impl EarlyLintPass for LiteralDigitGrouping {
+    fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
+        if in_macro(expr.span) {
+            return;
+        }
+        if let ExprKind::Lit(ref lit) = expr.node {
+            match lit.node {
+                LitKind::Int(..) |
+                LitKind::Float(..) |
+                LitKind::FloatUnsuffixed(..) => (),
+                _ => return,
+            }
+            if let Some(src) = snippet_opt(cx, lit.span) {
+                let literal = NumericLiteral::parse(&src);
+                if literal.is_unreadable(self.threshold) {
+                    span_lint_and_sugg(cx,
+                                       UNREADABLE_LITERAL,
+                                       lit.span,
+                                       "long literal lacking separators",
+                                       "consider grouping its digits",
+                                       literal.grouped());
+                } else if literal.is_inconsistent() {
+                    span_lint_and_sugg(cx,
+                                       INCONSISTENT_DIGIT_GROUPING,
+                                       lit.span,
+                                       "digits grouped inconsistently by underscores",
+                                       "consider grouping its digits evenly",
+                                       literal.grouped());
+                }
+            }
+        }
+    }
+}
+
+/// The parts of a numeric literal, as written in the source.
+struct NumericLiteral<'a> {
+    /// The radix prefix, eg. `0x`.
+    prefix: &'a str,
+    /// The digits before the decimal point, possibly with separators.
+    integer: &'a str,
+    /// The digits after the decimal point, if any.
+    fraction: Option<&'a str>,
+    /// The exponent and the type suffix, with their leading separator if any.
+    suffix: &'a str,
+    /// The number of digits of a group, `4` for hexadecimal and binary literals which are grouped
+    /// by nibbles.
+    group_size: usize,
+}
+
+impl<'a> NumericLiteral<'a> {
+    fn parse(src: &'a str) -> Self {
+        let (prefix, group_size) = if src.starts_with("0x") || src.starts_with("0b") {
+            (&src[..2], 4)
+        } else if src.starts_with("0o") {
+            (&src[..2], 3)
+        } else {
+            ("", 3)
+        };
+        let body = &src[prefix.len()..];
+
+        // `e` and `f` are digits in hexadecimal
+        let suffix_start = body.find(|c: char| match c {
+                'i' | 'u' => true,
+                'e' | 'E' | 'f' => prefix.is_empty(),
+                _ => false,
+            })
+            .unwrap_or_else(|| body.len());
+        let number = body[..suffix_start].trim_right_matches('_');
+        let (integer, fraction) = match number.find('.') {
+            Some(dot) => (&number[..dot], Some(&number[dot + 1..])),
+            None => (number, None),
+        };
+
+        NumericLiteral {
+            prefix: prefix,
+            integer: integer,
+            fraction: fraction,
+            suffix: &body[number.len()..],
+            group_size: group_size,
+        }
+    }
+
+    /// Does the integer or fractional part have more than `threshold` digits and no separator?
+    fn is_unreadable(&self, threshold: u64) -> bool {
+        let is_long = |digits: &str| digits.len() as u64 > threshold && !digits.contains('_');
+        is_long(self.integer) || self.fraction.map_or(false, &is_long)
+    }
+
+    /// Are the digits of the integer or fractional part separated in groups of varying sizes?
+    fn is_inconsistent(&self) -> bool {
+        !is_evenly_grouped(self.integer.split('_').rev()) ||
+        self.fraction.map_or(false, |fraction| !is_evenly_grouped(fraction.split('_')))
+    }
+
+    /// Format the literal with its digits grouped by `group_size`.
+    fn grouped(&self) -> String {
+        let mut grouped = self.prefix.to_owned();
+        grouped.push_str(&group_digits(&self.integer.replace('_', ""), self.group_size, true));
+        if let Some(fraction) = self.fraction {
+            grouped.push('.');
+            grouped.push_str(&group_digits(&fraction.replace('_', ""), self.group_size, false));
+        }
+        grouped.push_str(self.suffix);
+        grouped
+    }
+}
+
+/// Are all the `groups` of digits of the same size, but the last one which may be shorter? The
+/// groups are given starting from the decimal point.
+fn is_evenly_grouped<'a, I: Iterator<Item = &'a str>>(groups: I) -> bool {
+    let sizes = groups.map(str::len).collect::<Vec<_>>();
+    match sizes.split_last() {
+        Some((&last, init)) if !init.is_empty() => {
+            let size = init[0];
+            last != 0 && last <= size && init.iter().all(|&s| s == size)
+        },
+        _ => true,
+    }
+}
+
+/// Insert a `_` between each group of `size` digits, counting groups from the right for an integer
+/// part and from the left for a fractional part.
+fn group_digits(digits: &str, size: usize, from_right: bool) -> String {
+    let offset = if from_right {
+        (size - digits.len() % size) % size
+    } else {
+        0
+    };
+
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / size);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (i + offset) % size == 0 {
+            grouped.push('_');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
@@ -11,12 +11,15 @@ use rustc::ty;
 use rustc_const_eval::ConstContext;
 use std::collections::HashMap;
 use syntax::ast;
+use syntax::codemap::Span;
 use utils::sugg;
 
-use utils::{snippet, span_lint, get_parent_expr, match_trait_method, match_type, multispan_sugg, in_external_macro,
-            is_refutable, span_help_and_lint, is_integer_literal, get_enclosing_block, span_lint_and_then, higher,
-            last_path_segment, span_lint_and_sugg};
-use utils::paths;
+use utils::{snippet, span_lint, get_parent_expr, match_trait_method, match_type, match_any_type, multispan_sugg,
+            in_external_macro, is_refutable, span_help_and_lint, is_integer_literal, get_enclosing_block,
+            span_lint_and_then, higher, last_path_segment, span_lint_and_sugg, span_note_and_lint, method_chain_args,
+            get_trait_def_id, implements_trait, walk_ptrs_ty, used_in_expr, snippet_block, reindent_multiline};
+use utils::{paths, SpanlessEq};
+use utils::loop_body::{LoopBodyVisitor, LoopTarget, walk_loop_body};
 
 /// **What it does:** Checks for looping over the range of `0..len` of some
 /// collection just to get the values by index.
@@ -169,13 +172,16 @@ declare_lint! {
 /// the result.
 ///
 /// **Why is this bad?** It is more idiomatic to use a `for` loop over the
-/// iterator instead.
+/// iterator instead. When collecting into a `Result` or an `Option`, throwing
+/// away the result also silently discards the error (or `None`), which is
+/// most likely a bug.
 ///
 /// **Known problems:** None.
 ///
 /// **Example:**
 /// ```rust
 /// vec.iter().map(|x| /* some operation returning () */).collect::<Vec<_>>();
+/// vec.iter().map(|x| /* some fallible operation */).collect::<Result<Vec<_>, _>>();
 /// ```
 declare_lint! {
     pub UNUSED_COLLECT,
@@ -215,6 +221,9 @@ declare_lint! {
 ///
 /// **Known problems:** None.
 ///
+/// **Configuration:** Counters used more than `explicit-counter-max-uses`
+/// times in the loop body besides the increment are not linted.
+///
 /// **Example:**
 /// ```rust
 /// for i in 0..v.len() { foo(v[i]);
@@ -226,7 +235,9 @@ declare_lint! {
     "for-looping with an explicit counter when `_.enumerate()` would do"
 }
 
-/// **What it does:** Checks for empty `loop` expressions.
+/// **What it does:** Checks for empty `loop` expressions, and `while` loops
+/// with an empty body whose condition does not change anything, unlike e.g.
+/// `while it.next().is_some() {}`.
 ///
 /// **Why is this bad?** Those busy loops burn CPU cycles without doing
 /// anything. Think of the environment and either block on something or at least
@@ -237,11 +248,12 @@ declare_lint! {
 /// **Example:**
 /// ```rust
 /// loop {}
+/// while !flag.load(Ordering::SeqCst) {}
 /// ```
 declare_lint! {
     pub EMPTY_LOOP,
     Warn,
-    "empty `loop {}`, which should block or sleep"
+    "empty `loop {}` or `while cond {}`, which should block or sleep"
 }
 
 /// **What it does:** Checks for `while let` expressions on iterators.
@@ -261,6 +273,29 @@ declare_lint! {
     "using a while-let loop instead of a for loop on an iterator"
 }
 
+/// **What it does:** Checks for `while let Some(x) = <expr>.next()` loops
+/// where `<expr>` creates a new iterator over a collection or a string, e.g.
+/// `v.iter()` or `s.chars()`.
+///
+/// **Why is this bad?** The condition is evaluated anew on each iteration, so
+/// a fresh iterator is created each time and `next()` always returns its
+/// first element. Such a loop either never runs or never ends (unless it
+/// `break`s).
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// while let Some(x) = v.iter().next() {
+///     ..
+/// }
+/// ```
+declare_lint! {
+    pub WHILE_LET_ON_NEW_ITERATOR,
+    Warn,
+    "a `while let` loop calling `next()` on an iterator which is created on each iteration"
+}
+
 /// **What it does:** Checks for iterating a map (`HashMap` or `BTreeMap`) and
 /// ignoring either the keys or values.
 ///
@@ -302,8 +337,200 @@ declare_lint! {
     "any loop with an unconditional `break` statement"
 }
 
+/// **What it does:** Checks for `for` loops over an iterator `collect()`ed into
+/// a `HashSet` or `HashMap` which is then dropped after the loop.
+///
+/// **Why is this bad?** This allocates only to deduplicate the items, at the
+/// cost of losing their order. If that is not intended, iterating over the
+/// iterator directly is simpler and faster.
+///
+/// **Known problems:** Deduplication may well be intended, hence this lint is
+/// `Allow` by default.
+///
+/// **Example:**
+/// ```rust
+/// for x in v.iter().collect::<HashSet<_>>() { .. }
+/// ```
+declare_lint! {
+    pub HASH_COLLECT_LOOP,
+    Allow,
+    "looping over an iterator `collect()`ed into a `HashSet` or `HashMap`"
+}
+
+/// **What it does:** Checks for `for` loops over a range with constant bounds
+/// which unconditionally push into a `Vec` created with `Vec::new()` right
+/// before the loop.
+///
+/// **Why is this bad?** The number of pushed elements is known in advance, so
+/// the `Vec` could be allocated once with `Vec::with_capacity` instead of
+/// growing several times.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = Vec::new();
+/// for i in 0..10 {
+///     v.push(i);
+/// }
+/// ```
+declare_lint! {
+    pub PUSH_WITHOUT_RESERVE,
+    Allow,
+    "pushing a statically known number of elements into a `Vec` created without capacity"
+}
+
+/// **What it does:** Checks for `for` loops over a range with constant bounds,
+/// or of the form `0..n`, whose only statement pushes the same item into a
+/// `Vec`.
+///
+/// **Why is this bad?** This is more clearly and efficiently written with the
+/// `vec!` macro if the `Vec` is created with `Vec::new()` right before the
+/// loop, or with `Vec::extend` and `std::iter::repeat` otherwise.
+///
+/// **Known problems:** Only literals and local variables are considered as
+/// items, since `vec!` evaluates its item once and clones it.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = Vec::new();
+/// for _ in 0..10 {
+///     v.push(0);
+/// }
+/// ```
+/// could be written as
+/// ```rust
+/// let v = vec![0; 10];
+/// ```
+declare_lint! {
+    pub SAME_ITEM_PUSH,
+    Warn,
+    "pushing the same item into a `Vec` in a loop, which could be `vec![item; n]`"
+}
+
+/// **What it does:** Checks for `for (_, x) in it.enumerate()` loops where the
+/// index is ignored.
+///
+/// **Why is this bad?** The `enumerate` call is useless, iterating over `it`
+/// directly is simpler.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// for (_, x) in v.iter().enumerate() { .. }
+/// ```
+declare_lint! {
+    pub UNUSED_ENUMERATE_INDEX,
+    Warn,
+    "using `.enumerate()` and immediately dropping the index"
+}
+
+/// **What it does:** Checks for `String`s being accumulated with `+=` or
+/// `s = s + ..` inside a `for` loop.
+///
+/// **Why is this bad?** The appended values are often temporary `String`s
+/// (e.g. from `to_string()`), which are allocated just to be copied into the
+/// accumulator. Writing into the `String` with `write!` or `push_str`, or
+/// building it from an iterator, avoids these allocations.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut s = String::new();
+/// for x in 0..10 {
+///     s += &x.to_string();
+/// }
+/// ```
+declare_lint! {
+    pub STRING_ADD_IN_LOOP,
+    Allow,
+    "accumulating into a `String` with `+` in a `for` loop"
+}
+
+/// **What it does:** Checks for `for` loops whose body only increments a
+/// counter.
+///
+/// **Why is this bad?** `Iterator::count` does the same, and is clearer.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut count = 0;
+/// for _ in v.iter().filter(|x| x.is_some()) {
+///     count += 1;
+/// }
+/// ```
+/// Could be written as
+/// ```rust
+/// let count = v.iter().filter(|x| x.is_some()).count();
+/// ```
+declare_lint! {
+    pub MANUAL_COUNT,
+    Warn,
+    "a `for` loop only incrementing a counter, which could be `.count()`"
+}
+
+/// **What it does:** Checks for `for` loops over a `0..n` range whose loop
+/// variable is not used, i.e. loops which just repeat their body `n` times.
+///
+/// **Why is this bad?** It isn't, but such loops are easy to mistake for
+/// loops which use their counter. This lint only points them out.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// for _ in 0..n {
+///     do_something();
+/// }
+/// ```
+declare_lint! {
+    pub RANGE_REPEAT_LOOP,
+    Allow,
+    "a `for` loop over `0..n` which does not use the loop variable"
+}
+
+/// **What it does:** Checks for `for` loops over `Option`s whose whole body is
+/// an `if let Some(..)` on the loop variable.
+///
+/// **Why is this bad?** The loop only looks at the `Some` elements, which is
+/// more clearly expressed by filtering them out of the iterator.
+///
+/// **Known problems:** Loops over references to `Option`s are not linted.
+///
+/// **Example:**
+/// ```rust
+/// for x in v {
+///     if let Some(y) = x {
+///         println!("{}", y);
+///     }
+/// }
+/// ```
+/// Could be written:
+/// ```rust
+/// for y in v.into_iter().filter_map(|x| x) {
+///     println!("{}", y);
+/// }
+/// ```
+declare_lint! {
+    pub MANUAL_FLATTEN,
+    Warn,
+    "a `for` loop over `Option`s whose body is a single `if let Some(..)` on the loop variable"
+}
+
 #[derive(Copy, Clone)]
-pub struct Pass;
+pub struct Pass {
+    explicit_counter_max_uses: u64,
+}
+
+impl Pass {
+    pub fn new(explicit_counter_max_uses: u64) -> Self {
+        Pass { explicit_counter_max_uses: explicit_counter_max_uses }
+    }
+}
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
@@ -320,19 +547,39 @@ impl LintPass for Pass {
                     EMPTY_LOOP,
                     WHILE_LET_ON_ITERATOR,
                     FOR_KV_MAP,
-                    NEVER_LOOP)
+                    NEVER_LOOP,
+                    HASH_COLLECT_LOOP,
+                    PUSH_WITHOUT_RESERVE,
+                    SAME_ITEM_PUSH,
+                    UNUSED_ENUMERATE_INDEX,
+                    STRING_ADD_IN_LOOP,
+                    MANUAL_COUNT,
+                    RANGE_REPEAT_LOOP,
+                    WHILE_LET_ON_NEW_ITERATOR,
+                    MANUAL_FLATTEN)
     }
 }
 
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
         if let Some((pat, arg, body)) = higher::for_loop(expr) {
-            check_for_loop(cx, pat, arg, body, expr);
+            check_for_loop(cx, pat, arg, body, expr, self.explicit_counter_max_uses);
+        }
+        // check for empty `while cond {}` busy-waits; `while let` is desugared
+        // into a `loop` and thus not matched here
+        if let ExprWhile(ref cond, ref block, _) = expr.node {
+            if block.stmts.is_empty() && block.expr.is_none() && !has_side_effects(cx, cond) {
+                span_lint(cx,
+                          EMPTY_LOOP,
+                          expr.span,
+                          "empty `while` loop detected. You may want to block on something or add \
+                           `std::thread::sleep(..);` to the loop body.");
+            }
         }
         // check for `loop { if let {} else break }` that could be `while let`
         // (also matches an explicit "match" instead of "if let")
         // (even if the "match" or "if let" is used for declaration)
-        if let ExprLoop(ref block, _, LoopSource::Loop) = expr.node {
+        if let ExprLoop(ref block, label, LoopSource::Loop) = expr.node {
             // also check for empty `loop {}` statements
             if block.stmts.is_empty() && block.expr.is_none() {
                 span_lint(cx,
@@ -341,7 +588,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                           "empty `loop {}` detected. You may want to either use `panic!()` or add \
                            `std::thread::sleep(..);` to the loop body.");
             }
-            if never_loop_block(block) {
+            if never_loop_block(block) && !continues_loop(block, label.map(|label| label.node)) {
                 span_lint(cx, NEVER_LOOP, expr.span, "this loop never actually loops");
             }
 
@@ -390,17 +637,26 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 let iter_expr = &method_args[0];
                 let lhs_constructor = last_path_segment(qpath);
                 if method_name.node == "next" && match_trait_method(cx, match_expr, &paths::ITERATOR) &&
-                   lhs_constructor.name == "Some" && !is_refutable(cx, &pat_args[0]) &&
-                   !is_iterator_used_after_while_let(cx, iter_expr) {
-                    let iterator = snippet(cx, method_args[0].span, "_");
-                    let loop_var = snippet(cx, pat_args[0].span, "_");
-                    span_lint_and_then(cx,
-                                       WHILE_LET_ON_ITERATOR,
-                                       expr.span,
-                                       "this loop could be written as a `for` loop",
-                                       |db| {
-                        db.span_suggestion(expr.span, "try", format!("for {} in {} {{ .. }}", loop_var, iterator));
-                    });
+                   lhs_constructor.name == "Some" {
+                    if is_temporary_iterator(cx, iter_expr) {
+                        span_lint(cx,
+                                  WHILE_LET_ON_NEW_ITERATOR,
+                                  match_expr.span,
+                                  "the iterator is created anew on each iteration, this loop only ever looks at \
+                                   its first element");
+                    } else if !is_refutable(cx, &pat_args[0]) && !is_iterator_used_after_while_let(cx, iter_expr) {
+                        let iterator = snippet(cx, method_args[0].span, "_");
+                        let loop_var = snippet(cx, pat_args[0].span, "_");
+                        span_lint_and_then(cx,
+                                           WHILE_LET_ON_ITERATOR,
+                                           expr.span,
+                                           "this loop could be written as a `for` loop",
+                                           |db| {
+                            db.span_suggestion(expr.span,
+                                               "try",
+                                               format!("for {} in {} {{ .. }}", loop_var, iterator));
+                        });
+                    }
                 }
             }
         }
@@ -410,17 +666,85 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         if let StmtSemi(ref expr, _) = stmt.node {
             if let ExprMethodCall(ref method, _, ref args) = expr.node {
                 if args.len() == 1 && method.node == "collect" && match_trait_method(cx, expr, &paths::ITERATOR) {
-                    span_lint(cx,
-                              UNUSED_COLLECT,
-                              expr.span,
-                              "you are collect()ing an iterator and throwing away the result. \
-                               Consider using an explicit for loop to exhaust the iterator");
+                    let collected_ty = cx.tables.expr_ty(expr);
+                    if match_type(cx, collected_ty, &paths::RESULT) {
+                        span_lint(cx,
+                                  UNUSED_COLLECT,
+                                  expr.span,
+                                  "you are collect()ing an iterator into a `Result` and throwing away the result. \
+                                   Any error is silently discarded");
+                    } else if match_type(cx, collected_ty, &paths::OPTION) {
+                        span_lint(cx,
+                                  UNUSED_COLLECT,
+                                  expr.span,
+                                  "you are collect()ing an iterator into an `Option` and throwing away the result. \
+                                   A `None` is silently discarded");
+                    } else {
+                        span_lint(cx,
+                                  UNUSED_COLLECT,
+                                  expr.span,
+                                  "you are collect()ing an iterator and throwing away the result. \
+                                   Consider using an explicit for loop to exhaust the iterator");
+                    }
                 }
             }
         }
     }
 }
 
+/// Can `expr` change anything, by assigning or by borrowing mutably, e.g. in `it.next()`? A
+/// `while` condition which makes progress by itself, its loop is not a busy-wait.
+fn has_side_effects<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) -> bool {
+    struct SideEffectVisitor<'a, 'tcx: 'a> {
+        cx: &'a LateContext<'a, 'tcx>,
+        found: bool,
+    }
+
+    impl<'a, 'tcx> SideEffectVisitor<'a, 'tcx> {
+        fn is_mut_ref(&self, expr: &Expr) -> bool {
+            matches!(self.cx.tables.expr_ty_adjusted(expr).sty, ty::TyRef(_, ty::TypeAndMut { mutbl: MutMutable, .. }))
+        }
+    }
+
+    impl<'a, 'tcx> Visitor<'tcx> for SideEffectVisitor<'a, 'tcx> {
+        fn visit_expr(&mut self, expr: &'tcx Expr) {
+            match expr.node {
+                ExprAssign(..) | ExprAssignOp(..) | ExprAddrOf(MutMutable, _) => self.found = true,
+                ExprCall(_, ref args) |
+                ExprMethodCall(_, _, ref args) if args.iter().any(|arg| self.is_mut_ref(arg)) => self.found = true,
+                _ => walk_expr(self, expr),
+            }
+        }
+        fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = SideEffectVisitor {
+        cx: cx,
+        found: false,
+    };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
+/// Check whether a `continue` in the body of a loop labeled `label` refers to that loop.
+fn continues_loop(block: &Block, label: Option<Name>) -> bool {
+    struct ContinueVisitor {
+        found: bool,
+    }
+
+    impl<'tcx> LoopBodyVisitor<'tcx> for ContinueVisitor {
+        fn visit_continue(&mut self, _: &'tcx Expr, target: LoopTarget) {
+            self.found |= target == LoopTarget::Current;
+        }
+    }
+
+    let mut visitor = ContinueVisitor { found: false };
+    walk_loop_body(block, label, &mut visitor);
+    visitor.found
+}
+
 fn never_loop_block(block: &Block) -> bool {
     block.stmts.iter().any(never_loop_stmt) || block.expr.as_ref().map_or(false, |e| never_loop_expr(e))
 }
@@ -471,13 +795,21 @@ fn check_for_loop<'a, 'tcx>(
     pat: &'tcx Pat,
     arg: &'tcx Expr,
     body: &'tcx Expr,
-    expr: &'tcx Expr
+    expr: &'tcx Expr,
+    explicit_counter_max_uses: u64
 ) {
     check_for_loop_range(cx, pat, arg, body, expr);
     check_for_loop_reverse_range(cx, arg, expr);
     check_for_loop_arg(cx, pat, arg, expr);
-    check_for_loop_explicit_counter(cx, arg, body, expr);
+    check_for_loop_explicit_counter(cx, arg, body, expr, explicit_counter_max_uses);
     check_for_loop_over_map_kv(cx, pat, arg, body, expr);
+    check_for_loop_over_hash_collect(cx, arg);
+    check_for_loop_push_without_reserve(cx, arg, body, expr);
+    check_for_loop_same_item_push(cx, pat, arg, body, expr);
+    check_for_loop_unused_enumerate_index(cx, pat, arg, expr);
+    check_for_loop_string_add(cx, body);
+    check_for_loop_range_repeat(cx, pat, arg, body, expr);
+    check_for_loop_manual_flatten(cx, pat, arg, body, expr);
 }
 
 /// Check for looping over a range and then indexing a sequence with it.
@@ -502,7 +834,7 @@ fn check_for_loop_range<'a, 'tcx>(
 
             // linting condition: we only indexed one variable
             if visitor.indexed.len() == 1 {
-                let (indexed, indexed_extent) = visitor.indexed
+                let (indexed, (indexed_expr, indexed_extent)) = visitor.indexed
                     .into_iter()
                     .next()
                     .unwrap_or_else(|| unreachable!() /* len == 1 */);
@@ -524,7 +856,7 @@ fn check_for_loop_range<'a, 'tcx>(
                 };
 
                 let take = if let Some(end) = *end {
-                    if is_len_call(end, &indexed) {
+                    if is_len_call(cx, end, indexed_expr) {
                         "".to_owned()
                     } else {
                         match limits {
@@ -574,21 +906,35 @@ fn check_for_loop_range<'a, 'tcx>(
     }
 }
 
-fn is_len_call(expr: &Expr, var: &Name) -> bool {
+/// Check whether `expr` is `seq.len()`, possibly written as `(&seq).len()`.
+fn is_len_call(cx: &LateContext, expr: &Expr, seq: &Expr) -> bool {
     if_let_chain! {[
         let ExprMethodCall(method, _, ref len_args) = expr.node,
         len_args.len() == 1,
-        method.node == "len",
-        let ExprPath(QPath::Resolved(_, ref path)) = len_args[0].node,
-        path.segments.len() == 1,
-        path.segments[0].name == *var
+        method.node == "len"
     ], {
-        return true;
+        let recv = strip_addr_of(&len_args[0]);
+        // fast path for the common `v.len()`
+        if let (&ExprPath(QPath::Resolved(None, ref recv_path)), &ExprPath(QPath::Resolved(None, ref seq_path))) =
+            (&recv.node, &seq.node) {
+            if recv_path.segments.len() == 1 && seq_path.segments.len() == 1 {
+                return recv_path.segments[0].name == seq_path.segments[0].name;
+            }
+        }
+        return SpanlessEq::new(cx).eq_expr(recv, seq);
     }}
 
     false
 }
 
+/// Remove the `&`s in front of `expr`.
+fn strip_addr_of(mut expr: &Expr) -> &Expr {
+    while let ExprAddrOf(_, ref inner) = expr.node {
+        expr = inner;
+    }
+    expr
+}
+
 fn check_for_loop_reverse_range(cx: &LateContext, arg: &Expr, expr: &Expr) {
     // if this for loop is iterating over a two-sided range...
     if let Some(higher::Range { start: Some(start), end: Some(end), limits }) = higher::range(arg) {
@@ -608,13 +954,7 @@ fn check_for_loop_reverse_range(cx: &LateContext, arg: &Expr, expr: &Expr) {
                 };
 
                 if sup {
-                    let start_snippet = snippet(cx, start.span, "_");
-                    let end_snippet = snippet(cx, end.span, "_");
-                    let dots = if limits == ast::RangeLimits::Closed {
-                        "..."
-                    } else {
-                        ".."
-                    };
+                    let reversed = sugg::Sugg::hir(cx, end, "_").range(sugg::Sugg::hir(cx, start, "_"), limits);
 
                     span_lint_and_then(cx,
                                        REVERSE_RANGE_LOOP,
@@ -624,10 +964,7 @@ fn check_for_loop_reverse_range(cx: &LateContext, arg: &Expr, expr: &Expr) {
                         db.span_suggestion(arg.span,
                                            "consider using the following if you are attempting to iterate over this \
                                             range in reverse",
-                                           format!("({end}{dots}{start}).rev()",
-                                                   end = end_snippet,
-                                                   dots = dots,
-                                                   start = start_snippet));
+                                           format!("{}.rev()", reversed.maybe_par()));
                     });
                 } else if eq && limits != ast::RangeLimits::Closed {
                     // if they are equal, it's also problematic - this loop
@@ -705,6 +1042,332 @@ fn check_for_loop_arg(cx: &LateContext, pat: &Pat, arg: &Expr, expr: &Expr) {
     }
 }
 
+/// Check for `for x in iter.collect::<HashSet<_>>()`
+fn check_for_loop_over_hash_collect(cx: &LateContext, arg: &Expr) {
+    if let Some(arglists) = method_chain_args(arg, &["collect"]) {
+        let ty = cx.tables.expr_ty(arg);
+        let kind = if match_type(cx, ty, &paths::HASHSET) {
+            "HashSet"
+        } else if match_type(cx, ty, &paths::HASHMAP) {
+            "HashMap"
+        } else {
+            return;
+        };
+        span_note_and_lint(cx,
+                           HASH_COLLECT_LOOP,
+                           arg.span,
+                           &format!("collecting into a `{}` only to loop over it", kind),
+                           arg.span,
+                           &format!("this only deduplicates the items and loses their order, if that is not \
+                                     intended loop over `{}` directly",
+                                    snippet(cx, arglists[0][0].span, "_")));
+    }
+}
+
+/// Check for `let mut v = Vec::new(); for i in 0..10 { v.push(i); }`
+fn check_for_loop_push_without_reserve<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    arg: &'tcx Expr,
+    body: &'tcx Expr,
+    expr: &'tcx Expr
+) {
+    if_let_chain! {[
+        let Some(iterations) = constant_range_len(cx, arg),
+        let Some((var, _)) = single_push(cx, body),
+        let Some(init) = new_vec_before_loop(cx, var, expr)
+    ], {
+        span_lint_and_sugg(cx,
+                           PUSH_WITHOUT_RESERVE,
+                           init.span,
+                           "this `Vec` is filled with a known number of elements by the following loop",
+                           "consider reserving the capacity upfront",
+                           format!("Vec::with_capacity({})", iterations));
+    }}
+}
+
+/// Check for `let mut v = Vec::new(); for _ in 0..10 { v.push(x); }`
+fn check_for_loop_same_item_push<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    pat: &'tcx Pat,
+    arg: &'tcx Expr,
+    body: &'tcx Expr,
+    expr: &'tcx Expr
+) {
+    // the push must be the only statement of the loop, so the pushed item can only depend on the
+    // loop variable
+    if let ExprBlock(ref block) = body.node {
+        if block.stmts.len() != 1 || block.expr.is_some() {
+            return;
+        }
+    }
+    if_let_chain! {[
+        let Some(iterations) = range_len_snippet(cx, arg),
+        let Some((var, item)) = single_push(cx, body),
+        is_same_item(cx, pat, item),
+        match_type(cx, walk_ptrs_ty(cx.tables.node_id_to_type(var)), &paths::VEC),
+        let Some(clone_trait) = get_trait_def_id(cx, &paths::CLONE_TRAIT),
+        implements_trait(cx, cx.tables.expr_ty(item), clone_trait, &[], None)
+    ], {
+        let help = if let Some(init) = new_vec_before_loop(cx, var, expr) {
+            format!("try using `vec![{}; {}]` instead of `{}` and the loop",
+                    snippet(cx, item.span, ".."),
+                    iterations,
+                    snippet(cx, init.span, ".."))
+        } else {
+            format!("try using `{}.extend(std::iter::repeat({}).take({}))` instead of the loop",
+                    cx.tcx.hir.name(var),
+                    snippet(cx, item.span, ".."),
+                    iterations)
+        };
+        span_help_and_lint(cx,
+                           SAME_ITEM_PUSH,
+                           expr.span,
+                           "it looks like the same item is being pushed into this `Vec`",
+                           &help);
+    }}
+}
+
+/// Return the number of iterations of a range as a snippet, either if it has constant bounds or
+/// if it is of the form `0..n`.
+fn range_len_snippet(cx: &LateContext, arg: &Expr) -> Option<String> {
+    if let Some(len) = constant_range_len(cx, arg) {
+        return Some(len.to_string());
+    }
+    if let Some(higher::Range { start: Some(start), end: Some(end), limits: ast::RangeLimits::HalfOpen }) =
+        higher::range(arg) {
+        if is_integer_literal(start, 0) {
+            // the length is used as a `usize` in the suggestion
+            return Some(if cx.tables.expr_ty(end).sty == ty::TyUint(ast::UintTy::Us) {
+                snippet(cx, end.span, "..").into_owned()
+            } else {
+                sugg::Sugg::hir(cx, end, "..").as_ty("usize").to_string()
+            });
+        }
+    }
+    None
+}
+
+/// Check whether `item` is a literal, or a local which is not the loop variable bound by `pat`.
+fn is_same_item<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat, item: &'tcx Expr) -> bool {
+    match item.node {
+        ExprLit(..) => true,
+        ExprPath(..) if var_def_id(cx, item).is_some() => {
+            if let PatKind::Binding(_, _, ref ident, _) = pat.node {
+                let mut visitor = UsedVisitor {
+                    var: ident.node,
+                    used: false,
+                    cx: cx,
+                };
+                walk_expr(&mut visitor, item);
+                !visitor.used
+            } else {
+                true
+            }
+        },
+        _ => false,
+    }
+}
+
+/// If the loop `body` pushes exactly once, and not conditionally, into a local, return the
+/// `NodeId` of the local and the pushed expression. A `break`, `continue` or `return` anywhere in
+/// the body could skip the push, so the body must not contain any.
+fn single_push<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, body: &'tcx Expr) -> Option<(NodeId, &'tcx Expr)> {
+    let (var, item) = if let ExprBlock(ref block) = body.node {
+        let mut pushes = block.stmts.iter().filter_map(|stmt| match stmt.node {
+            StmtExpr(ref e, _) | StmtSemi(ref e, _) => push_call(cx, e),
+            StmtDecl(..) => None,
+        });
+        match (pushes.next(), pushes.next()) {
+            (Some(push), None) => push,
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+    let mut visitor = PushVisitor {
+        cx: cx,
+        var: var,
+        pushes: 0,
+        jumps: false,
+    };
+    walk_expr(&mut visitor, body);
+    if visitor.pushes == 1 && !visitor.jumps {
+        Some((var, item))
+    } else {
+        None
+    }
+}
+
+/// If the statement just before the loop `expr` declares `var` as `Vec::new()`, so that it is
+/// still empty when entering the loop, return the initializer.
+fn new_vec_before_loop<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, var: NodeId, expr: &'tcx Expr) -> Option<&'tcx Expr> {
+    let contains_loop = |span: Span| span.lo <= expr.span.lo && expr.span.hi <= span.hi;
+    let map = &cx.tcx.hir;
+    let parent_scope = map.get_enclosing_scope(expr.id).and_then(|id| map.get_enclosing_scope(id));
+    if_let_chain! {[
+        let Some(parent_id) = parent_scope,
+        let NodeBlock(block) = map.get(parent_id),
+        let Some(pos) = block.stmts.iter().position(|stmt| contains_loop(stmt.span)).or_else(|| {
+            block.expr.as_ref().and_then(|e| if contains_loop(e.span) { Some(block.stmts.len()) } else { None })
+        }),
+        pos > 0,
+        let StmtDecl(ref decl, _) = block.stmts[pos - 1].node,
+        let DeclLocal(ref local) = decl.node,
+        local.pat.id == var,
+        let Some(ref init) = local.init,
+        let ExprCall(ref fun, ref args) = init.node,
+        args.is_empty(),
+        let ExprPath(ref qpath) = fun.node,
+        last_path_segment(qpath).name == "new",
+        match_type(cx, cx.tables.expr_ty(init), &paths::VEC)
+    ], {
+        return Some(init);
+    }}
+    None
+}
+
+/// Return the number of iterations of a range with constant, non-negative bounds.
+fn constant_range_len(cx: &LateContext, arg: &Expr) -> Option<u128> {
+    if let Some(higher::Range { start: Some(start), end: Some(end), limits }) = higher::range(arg) {
+        let constcx = ConstContext::with_tables(cx.tcx, cx.tables);
+        if let (Ok(ConstVal::Integral(start_idx)), Ok(ConstVal::Integral(end_idx))) =
+            (constcx.eval(start), constcx.eval(end)) {
+            if start_idx.is_negative() || end_idx.is_negative() || start_idx > end_idx {
+                return None;
+            }
+            let len = end_idx.to_u128_unchecked() - start_idx.to_u128_unchecked();
+            return if limits == ast::RangeLimits::Closed {
+                len.checked_add(1)
+            } else {
+                Some(len)
+            };
+        }
+    }
+    None
+}
+
+/// If `expr` is `v.push(x)` with `v` a local, return the `NodeId` of `v` and `x`.
+fn push_call<'a>(cx: &LateContext, expr: &'a Expr) -> Option<(NodeId, &'a Expr)> {
+    if let ExprMethodCall(ref name, _, ref args) = expr.node {
+        if name.node == "push" && args.len() == 2 {
+            return var_def_id(cx, &args[0]).map(|var| (var, &args[1]));
+        }
+    }
+    None
+}
+
+/// Check for `for (_, x) in it.enumerate()`
+fn check_for_loop_unused_enumerate_index(cx: &LateContext, pat: &Pat, arg: &Expr, expr: &Expr) {
+    if_let_chain! {[
+        let PatKind::Tuple(ref pats, _) = pat.node,
+        pats.len() == 2,
+        pats[0].node == PatKind::Wild,
+        let ExprMethodCall(ref method, _, ref args) = arg.node,
+        method.node == "enumerate" && args.len() == 1,
+        match_trait_method(cx, arg, &paths::ITERATOR)
+    ], {
+        span_lint_and_then(cx,
+                           UNUSED_ENUMERATE_INDEX,
+                           expr.span,
+                           "you seem to use `.enumerate()` and immediately discard the index",
+                           |db| {
+            multispan_sugg(db,
+                           "remove the `.enumerate()` call".to_string(),
+                           vec![(pat.span, snippet(cx, pats[1].span, "..").into_owned()),
+                                (arg.span, snippet(cx, args[0].span, "..").into_owned())]);
+        });
+    }}
+}
+
+/// Check for `for _ in 0..n` loops which don't use the loop variable.
+fn check_for_loop_range_repeat<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    pat: &'tcx Pat,
+    arg: &'tcx Expr,
+    body: &'tcx Expr,
+    expr: &Expr
+) {
+    if_let_chain! {[
+        let Some(higher::Range { start: Some(start), end: Some(end), limits: ast::RangeLimits::HalfOpen }) =
+            higher::range(arg),
+        is_integer_literal(start, 0),
+    ], {
+        let unused = match pat.node {
+            PatKind::Binding(_, def_id, _, None) => !used_in_expr(cx, def_id, body),
+            ref pat => pat_is_wild(cx, pat, body),
+        };
+        if unused {
+            span_lint(cx,
+                      RANGE_REPEAT_LOOP,
+                      Span { hi: arg.span.hi, ..expr.span },
+                      &format!("this loop just repeats its body `{}` times", snippet(cx, end.span, "n")));
+        }
+    }}
+}
+
+/// Check for `for x in it { if let Some(y) = x { .. } }` loops.
+fn check_for_loop_manual_flatten<'a, 'tcx>(
+    cx: &LateContext<'a, 'tcx>,
+    pat: &'tcx Pat,
+    arg: &'tcx Expr,
+    body: &'tcx Expr,
+    expr: &Expr
+) {
+    if_let_chain! {[
+        let PatKind::Binding(_, def_id, _, None) = pat.node,
+        let ExprBlock(ref block) = body.node,
+        let Some(inner) = single_expr(block),
+        let ExprMatch(ref scrutinee, ref arms, MatchSource::IfLetDesugar { contains_else_clause: false }) =
+            inner.node,
+        var_def_id(cx, scrutinee) == Some(pat.id),
+        match_type(cx, cx.tables.expr_ty(scrutinee), &paths::OPTION),
+        arms[0].pats.len() == 1 && arms[0].guard.is_none(),
+        let PatKind::TupleStruct(ref qpath, ref pat_args, None) = arms[0].pats[0].node,
+        last_path_segment(qpath).name == "Some" && pat_args.len() == 1,
+        !used_in_expr(cx, def_id, &arms[0].body),
+    ], {
+        let is_iterator = get_trait_def_id(cx, &paths::ITERATOR)
+            .map_or(false, |id| implements_trait(cx, cx.tables.expr_ty(arg), id, &[], None));
+        let iter = if is_iterator {
+            format!("{}", sugg::Sugg::hir(cx, arg, "_").maybe_par())
+        } else {
+            format!("{}.into_iter()", sugg::Sugg::hir(cx, arg, "_").maybe_par())
+        };
+
+        span_lint_and_then(cx,
+                           MANUAL_FLATTEN,
+                           expr.span,
+                           "this loop only looks at the `Some` elements of its iterator",
+                           |db| {
+            let sugg = format!("for {} in {}.filter_map(|x| x) {}",
+                               snippet(cx, pat_args[0].span, ".."),
+                               iter,
+                               snippet_block(cx, arms[0].body.span, ".."));
+            db.span_suggestion(expr.span,
+                               "filter them out of the iterator",
+                               reindent_multiline(cx, &sugg, expr.span));
+        });
+    }}
+}
+
+/// Check for `s += ..` or `s = s + ..` on a `String` in the loop body.
+fn check_for_loop_string_add<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, body: &'tcx Expr) {
+    let mut visitor = StringAddVisitor {
+        cx: cx,
+        spans: vec![],
+    };
+    walk_expr(&mut visitor, body);
+
+    for span in visitor.spans {
+        span_help_and_lint(cx,
+                           STRING_ADD_IN_LOOP,
+                           span,
+                           "you seem to be accumulating into a `String` with `+` in a loop",
+                           "consider using `write!` or `push_str` on the `String`, or building it from an \
+                            iterator with `collect` or `extend`");
+    }
+}
+
 /// Check for `for` loops over `Option`s and `Results`
 fn check_arg_type(cx: &LateContext, pat: &Pat, arg: &Expr) {
     let ty = cx.tables.expr_ty(arg);
@@ -735,12 +1398,14 @@ fn check_for_loop_explicit_counter<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
     arg: &'tcx Expr,
     body: &'tcx Expr,
-    expr: &'tcx Expr
+    expr: &'tcx Expr,
+    max_uses: u64
 ) {
     // Look for variables that are incremented once per loop iteration.
     let mut visitor = IncrementVisitor {
         cx: cx,
         states: HashMap::new(),
+        uses: HashMap::new(),
         depth: 0,
         done: false,
     };
@@ -766,13 +1431,17 @@ fn check_for_loop_explicit_counter<'a, 'tcx>(
 
                 if visitor2.state == VarState::Warn {
                     if let Some(name) = visitor2.name {
-                        span_lint(cx,
-                                  EXPLICIT_COUNTER_LOOP,
-                                  expr.span,
-                                  &format!("the variable `{0}` is used as a loop counter. Consider using `for ({0}, \
-                                            item) in {1}.enumerate()` or similar iterators",
-                                           name,
-                                           snippet(cx, arg.span, "_")));
+                        if is_pure_count(cx, body, *id) {
+                            lint_manual_count(cx, arg, expr, name);
+                        } else if visitor.uses.get(id).cloned().unwrap_or(0) <= max_uses {
+                            span_lint(cx,
+                                      EXPLICIT_COUNTER_LOOP,
+                                      expr.span,
+                                      &format!("the variable `{0}` is used as a loop counter. Consider using `for \
+                                                ({0}, item) in {1}.enumerate()` or similar iterators",
+                                               name,
+                                               snippet(cx, arg.span, "_")));
+                        }
                     }
                 }
             }
@@ -780,6 +1449,50 @@ fn check_for_loop_explicit_counter<'a, 'tcx>(
     }
 }
 
+/// Check whether the loop body does nothing but increment the `usize` variable `var` by one.
+fn is_pure_count(cx: &LateContext, body: &Expr, var: NodeId) -> bool {
+    let incr = match body.node {
+        ExprBlock(ref block) => {
+            match (&*block.stmts, block.expr.as_ref()) {
+                (&[], Some(e)) => &**e,
+                (&[ref stmt], None) => {
+                    if let StmtSemi(ref e, _) = stmt.node {
+                        &**e
+                    } else {
+                        return false;
+                    }
+                },
+                _ => return false,
+            }
+        },
+        _ => return false,
+    };
+
+    if let ExprAssignOp(op, ref lhs, ref rhs) = incr.node {
+        op.node == BiAdd && is_integer_literal(rhs, 1) && var_def_id(cx, lhs) == Some(var) &&
+        cx.tables.node_id_to_type(var).sty == ty::TyUint(ast::UintTy::Us)
+    } else {
+        false
+    }
+}
+
+fn lint_manual_count(cx: &LateContext, arg: &Expr, expr: &Expr, name: Name) {
+    let is_iterator = get_trait_def_id(cx, &paths::ITERATOR)
+        .map_or(false, |id| implements_trait(cx, cx.tables.expr_ty(arg), id, &[], None));
+    let iter = if is_iterator {
+        format!("{}", sugg::Sugg::hir(cx, arg, "_").maybe_par())
+    } else {
+        format!("{}.into_iter()", sugg::Sugg::hir(cx, arg, "_").maybe_par())
+    };
+
+    span_lint_and_sugg(cx,
+                       MANUAL_COUNT,
+                       expr.span,
+                       &format!("the variable `{}` is only used to count the iterations of this loop", name),
+                       "consider using `count`",
+                       format!("{} += {}.count();", name, iter));
+}
+
 /// Check for the `FOR_KV_MAP` lint.
 fn check_for_loop_over_map_kv<'a, 'tcx>(
     cx: &LateContext<'a, 'tcx>,
@@ -812,7 +1525,7 @@ fn check_for_loop_over_map_kv<'a, 'tcx>(
                 _ => arg,
             };
 
-            if match_type(cx, ty, &paths::HASHMAP) || match_type(cx, ty, &paths::BTREEMAP) {
+            if match_any_type(cx, ty, &[&paths::HASHMAP, &paths::BTREEMAP]) {
                 span_lint_and_then(cx,
                                    FOR_KV_MAP,
                                    expr.span,
@@ -872,7 +1585,7 @@ impl<'a, 'tcx: 'a> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
 struct VarVisitor<'a, 'tcx: 'a> {
     cx: &'a LateContext<'a, 'tcx>, // context reference
     var: DefId, // var name to look for as index
-    indexed: HashMap<Name, Option<CodeExtent>>, // indexed variables, the extent is None for global
+    indexed: HashMap<String, (&'a Expr, Option<CodeExtent>)>, // indexed sequences, the extent is None for global
     nonindex: bool, // has the var been used otherwise?
 }
 
@@ -885,22 +1598,20 @@ impl<'a, 'tcx> Visitor<'tcx> for VarVisitor<'a, 'tcx> {
                     if_let_chain! {[
                         let Some(parexpr) = get_parent_expr(self.cx, expr),
                         let ExprIndex(ref seqexpr, _) = parexpr.node,
-                        let ExprPath(ref seqpath) = seqexpr.node,
-                        let QPath::Resolved(None, ref seqvar) = *seqpath,
-                        seqvar.segments.len() == 1
+                        let Some((seqpath, seqvar_id, name)) = indexed_sequence(seqexpr)
                     ], {
-                        let def = self.cx.tables.qpath_def(seqpath, seqexpr.id);
+                        let def = self.cx.tables.qpath_def(seqpath, seqvar_id);
                         match def {
                             Def::Local(..) | Def::Upvar(..) => {
                                 let def_id = def.def_id();
                                 let node_id = self.cx.tcx.hir.as_local_node_id(def_id).expect("local/upvar are local nodes");
 
                                 let extent = self.cx.tcx.region_maps.var_scope(node_id);
-                                self.indexed.insert(seqvar.segments[0].name, Some(extent));
+                                self.indexed.insert(name, (&**seqexpr, Some(extent)));
                                 return;  // no need to walk further
                             }
                             Def::Static(..) | Def::Const(..) => {
-                                self.indexed.insert(seqvar.segments[0].name, None);
+                                self.indexed.insert(name, (&**seqexpr, None));
                                 return;  // no need to walk further
                             }
                             _ => (),
@@ -919,6 +1630,50 @@ impl<'a, 'tcx> Visitor<'tcx> for VarVisitor<'a, 'tcx> {
     }
 }
 
+/// If the indexed expression `expr` is a single name, or a field of one like `self.v`, return the
+/// path to the variable, the id of the path expression and the sequence as it should be written in
+/// suggestions.
+fn indexed_sequence(expr: &Expr) -> Option<(&QPath, NodeId, String)> {
+    let (var, field) = match expr.node {
+        ExprField(ref base, ref field) => (&**base, Some(field.node)),
+        _ => (expr, None),
+    };
+    if_let_chain! {[
+        let ExprPath(ref qpath) = var.node,
+        let QPath::Resolved(None, ref path) = *qpath,
+        path.segments.len() == 1
+    ], {
+        let name = path.segments[0].name;
+        return Some((qpath, var.id, field.map_or_else(|| name.to_string(), |field| format!("{}.{}", name, field))));
+    }}
+    None
+}
+
+/// Check whether `expr` creates a new iterator over a collection or string stored in a place,
+/// possibly through some adapters, e.g. `v.iter()` or `s.chars().skip(1)`. Iterators advancing
+/// some other state, like `it.by_ref()` or `reader.bytes()`, are not considered.
+fn is_temporary_iterator(cx: &LateContext, expr: &Expr) -> bool {
+    if let ExprMethodCall(ref name, _, ref args) = expr.node {
+        let recv = &args[0];
+        let is_place = matches!(recv.node, ExprPath(_) | ExprField(..) | ExprTupField(..));
+        let recv_ty = walk_ptrs_ty(cx.tables.expr_ty(recv));
+        match &*name.node.as_str() {
+            "iter" | "iter_mut" => {
+                is_place &&
+                (matches!(recv_ty.sty, ty::TySlice(..) | ty::TyArray(..)) ||
+                 match_any_type(cx, recv_ty, &REF_ITERABLE_COLLECTIONS))
+            },
+            "bytes" | "chars" | "char_indices" => {
+                is_place && (recv_ty.sty == ty::TyStr || match_type(cx, recv_ty, &paths::STRING))
+            },
+            "cloned" | "enumerate" | "filter" | "map" | "rev" | "skip" | "take" => is_temporary_iterator(cx, recv),
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
 fn is_iterator_used_after_while_let<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, iter_expr: &'tcx Expr) -> bool {
     let def_id = match var_def_id(cx, iter_expr) {
         Some(id) => id,
@@ -962,22 +1717,26 @@ impl<'a, 'tcx> Visitor<'tcx> for VarUsedAfterLoopVisitor<'a, 'tcx> {
 }
 
 
+/// Collections which provide `IntoIterator` impls for `&T` and `&mut T`.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const REF_ITERABLE_COLLECTIONS: [&'static [&'static str]; 8] = [
+    &paths::VEC,
+    &paths::LINKED_LIST,
+    &paths::HASHMAP,
+    &paths::HASHSET,
+    &paths::VEC_DEQUE,
+    &paths::BINARY_HEAP,
+    &paths::BTREEMAP,
+    &paths::BTREESET,
+];
+
 /// Return true if the type of expr is one that provides `IntoIterator` impls
 /// for `&T` and `&mut T`, such as `Vec`.
-#[cfg_attr(rustfmt, rustfmt_skip)]
 fn is_ref_iterable_type(cx: &LateContext, e: &Expr) -> bool {
     // no walk_ptrs_ty: calling iter() on a reference can make sense because it
     // will allow further borrows afterwards
     let ty = cx.tables.expr_ty(e);
-    is_iterable_array(ty) ||
-    match_type(cx, ty, &paths::VEC) ||
-    match_type(cx, ty, &paths::LINKED_LIST) ||
-    match_type(cx, ty, &paths::HASHMAP) ||
-    match_type(cx, ty, &paths::HASHSET) ||
-    match_type(cx, ty, &paths::VEC_DEQUE) ||
-    match_type(cx, ty, &paths::BINARY_HEAP) ||
-    match_type(cx, ty, &paths::BTREEMAP) ||
-    match_type(cx, ty, &paths::BTREESET)
+    is_iterable_array(ty) || match_any_type(cx, ty, &REF_ITERABLE_COLLECTIONS)
 }
 
 fn is_iterable_array(ty: ty::Ty) -> bool {
@@ -1023,6 +1782,21 @@ fn extract_first_expr(block: &Block) -> Option<&Expr> {
     }
 }
 
+/// If a block consists of a single expression (with or without semicolon), return it.
+fn single_expr(block: &Block) -> Option<&Expr> {
+    match (&block.stmts[..], &block.expr) {
+        (&[], &Some(ref expr)) => Some(expr),
+        (&[ref stmt], &None) => {
+            match stmt.node {
+                StmtExpr(ref expr, _) |
+                StmtSemi(ref expr, _) => Some(expr),
+                StmtDecl(..) => None,
+            }
+        },
+        _ => None,
+    }
+}
+
 /// Return true if expr contains a single break expr (maybe within a block).
 fn is_break_expr(expr: &Expr) -> bool {
     match expr.node {
@@ -1049,10 +1823,74 @@ enum VarState {
     DontWarn,
 }
 
+/// Count the pushes into a given variable, and look for anything that could skip them.
+struct PushVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    var: NodeId,
+    pushes: usize,
+    jumps: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for PushVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprBreak(..) | ExprAgain(..) | ExprRet(..) => self.jumps = true,
+            _ => {
+                if push_call(self.cx, expr).map(|(var, _)| var) == Some(self.var) {
+                    self.pushes += 1;
+                }
+            },
+        }
+        walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
+/// Collects `String` additions (`s += ..` or `s = s + ..`), except those in inner `for` loops.
+struct StringAddVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    spans: Vec<Span>,
+}
+
+impl<'a, 'tcx> StringAddVisitor<'a, 'tcx> {
+    fn is_string(&self, expr: &Expr) -> bool {
+        match_type(self.cx, walk_ptrs_ty(self.cx.tables.expr_ty(expr)), &paths::STRING)
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for StringAddVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        // an inner `for` loop is checked on its own
+        if higher::for_loop(expr).is_some() {
+            return;
+        }
+        match expr.node {
+            ExprAssignOp(op, ref lhs, _) if op.node == BiAdd && self.is_string(lhs) => {
+                self.spans.push(expr.span);
+            },
+            ExprAssign(ref lhs, ref rhs) => {
+                if let ExprBinary(op, ref left, _) = rhs.node {
+                    if op.node == BiAdd && self.is_string(lhs) && SpanlessEq::new(self.cx).eq_expr(lhs, left) {
+                        self.spans.push(expr.span);
+                    }
+                }
+            },
+            _ => (),
+        }
+        walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
+
 /// Scan a for loop for variables that are incremented exactly once.
 struct IncrementVisitor<'a, 'tcx: 'a> {
     cx: &'a LateContext<'a, 'tcx>, // context reference
     states: HashMap<NodeId, VarState>, // incremented variables
+    uses: HashMap<NodeId, u64>, // number of uses of the variables besides `+= 1`
     depth: u32, // depth of conditional expressions
     done: bool,
 }
@@ -1066,6 +1904,16 @@ impl<'a, 'tcx> Visitor<'tcx> for IncrementVisitor<'a, 'tcx> {
         // If node is a variable
         if let Some(def_id) = var_def_id(self.cx, expr) {
             if let Some(parent) = get_parent_expr(self.cx, expr) {
+                let is_increment = match parent.node {
+                    ExprAssignOp(op, ref lhs, ref rhs) => {
+                        lhs.id == expr.id && op.node == BiAdd && is_integer_literal(rhs, 1)
+                    },
+                    _ => false,
+                };
+                if !is_increment {
+                    *self.uses.entry(def_id).or_insert(0) += 1;
+                }
+
                 let state = self.states.entry(def_id).or_insert(VarState::Initial);
 
                 match parent.node {
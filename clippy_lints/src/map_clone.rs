@@ -55,6 +55,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                             else if let ExprMethodCall(clone_call, _, ref clone_args) = closure_expr.node {
                                 if clone_call.node == "clone" &&
                                     clone_args.len() == 1 &&
+                                    // `.cloned()` needs references, cloning owned values is another matter
+                                    walk_ptrs_ty_depth(cx.tables.pat_ty(&first_arg.pat)).1 >= 1 &&
                                     match_trait_method(cx, closure_expr, &paths::CLONE_TRAIT) &&
                                     expr_eq_name(&clone_args[0], arg_ident)
                                 {
@@ -1,16 +1,20 @@
 use rustc::hir::*;
+use rustc::hir::def;
+use rustc::hir::map::Node::NodeStmt;
 use rustc::lint::*;
 use rustc::middle::const_val::ConstVal;
 use rustc::ty;
 use rustc_const_eval::ConstContext;
 use rustc_const_math::ConstInt;
-use std::cmp::Ordering;
+use std::cmp::{self, Ordering};
 use std::collections::Bound;
-use syntax::ast::LitKind;
+use syntax::ast::{LitKind, Name, NodeId};
 use syntax::codemap::Span;
 use utils::paths;
-use utils::{match_type, snippet, span_note_and_lint, span_lint_and_then, in_external_macro, expr_block, walk_ptrs_ty,
-            is_expn_of, remove_blocks};
+use utils::{match_type, snippet, span_lint, span_help_and_lint, span_note_and_lint, span_lint_and_then,
+            span_lint_and_sugg, span_lint_and_then_unless_macro, in_external_macro, expr_block, walk_ptrs_ty,
+            is_expn_of, is_refutable, remove_blocks, contains_return, match_def_path, reindent_multiline,
+            snippet_block, used_in_expr, return_ty, same_tys, SpanlessEq};
 use utils::sugg::Sugg;
 
 /// **What it does:** Checks for matches with a single arm where an `if let`
@@ -143,6 +147,191 @@ declare_lint! {
     "a match with `Err(_)` arm and take drastic actions"
 }
 
+/// **What it does:** Checks for empty match arm bodies which are not written in
+/// the preferred style, i.e. `_ => {}` when `_ => ()` is preferred (or vice
+/// versa). The preferred form can be configured with the `unit-arm-style`
+/// option.
+///
+/// **Why is this bad?** Mixing both forms is a needless inconsistency.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// match x {
+///     Some(y) => foo(y),
+///     None => {},
+/// }
+/// ```
+declare_lint! {
+    pub UNIT_ARM_STYLE,
+    Allow,
+    "an empty match arm body written as `{}` instead of `()` (or vice versa, depending on the configuration)"
+}
+
+/// **What it does:** Checks for matches with a single arm destructuring a
+/// type which has only one variant, e.g. a newtype-style enum.
+///
+/// **Why is this bad?** Such a match can never fail, a `let` binding does the
+/// same with less nesting.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// enum Wrapper {
+///     Data(i32),
+/// }
+///
+/// match wrapper {
+///     Wrapper::Data(i) => foo(i),
+/// }
+/// ```
+/// Could be written as
+/// ```rust
+/// let Wrapper::Data(i) = wrapper;
+/// foo(i);
+/// ```
+declare_lint! {
+    pub INFALLIBLE_DESTRUCTURING_MATCH,
+    Warn,
+    "a `match` with a single irrefutable arm, which could be a `let` binding"
+}
+
+/// **What it does:** Checks for `match` arms which can never be reached
+/// because of a previous guarded arm: either an arm matching everything with
+/// an `if true` guard, or an arm with the very same patterns and guard.
+///
+/// **Why is this bad?** Such arms can never be reached, which is most likely
+/// not what the author intended. The compiler's `unreachable_patterns` lint
+/// ignores guarded arms, so it does not catch these.
+///
+/// **Known problems:** Guards are only compared syntactically, a guard with
+/// side effects could make the repeated arm reachable.
+///
+/// **Example:**
+/// ```rust
+/// match x {
+///     Some(y) if y > 0 => foo(y),
+///     Some(y) if y > 0 => bar(y),
+///     _ => baz(),
+/// }
+/// ```
+declare_lint! {
+    pub UNREACHABLE_MATCH_ARM,
+    Allow,
+    "a `match` arm which can never be reached because of a previous guarded arm"
+}
+
+/// **What it does:** Checks for matches on an `Option` which map the
+/// contained value and leave `None` alone, i.e. a manual implementation of
+/// `Option::map`.
+///
+/// **Why is this bad?** `Option::map` is shorter and expresses the intent
+/// directly.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// match x {
+///     Some(y) => Some(y + 1),
+///     None => None,
+/// }
+/// ```
+/// Could be written as
+/// ```rust
+/// x.map(|y| y + 1)
+/// ```
+declare_lint! {
+    pub MANUAL_MAP,
+    Warn,
+    "a `match` on an `Option` which could be written with `Option::map`"
+}
+
+/// **What it does:** Checks for `name @ pattern` bindings in `match` arms
+/// where `name` is never used, neither in the guard nor in the arm's body.
+///
+/// **Why is this bad?** The binding is just noise, the sub-pattern alone
+/// matches the same values.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// match x {
+///     Some(y @ 1...9) => digit(),
+///     _ => other(),
+/// }
+/// ```
+/// Could be written as
+/// ```rust
+/// match x {
+///     Some(1...9) => digit(),
+///     _ => other(),
+/// }
+/// ```
+declare_lint! {
+    pub REDUNDANT_PATTERN_BINDING,
+    Warn,
+    "a `name @ pattern` binding in a `match` arm where `name` is never used"
+}
+
+/// **What it does:** Checks for `match`es on a `Result` which return the
+/// error and evaluate to the `Ok` value, i.e. which do what the `?` operator
+/// does.
+///
+/// **Why is this bad?** The `?` operator is shorter and is what readers expect
+/// for propagating errors.
+///
+/// **Known problems:** `?` converts the error with `From::from`, so an error
+/// converted with `.into()` might need a `From` implementation.
+///
+/// **Example:**
+/// ```rust
+/// let x = match foo() {
+///     Ok(x) => x,
+///     Err(e) => return Err(e),
+/// };
+/// ```
+/// Could be written as
+/// ```rust
+/// let x = foo()?;
+/// ```
+declare_lint! {
+    pub MATCH_QUESTION_MARK,
+    Warn,
+    "a `match` on a `Result` which could be written with the `?` operator"
+}
+
+/// **What it does:** Checks for match arms binding the matched value only to
+/// compare it to a literal in the guard, like `n if n == 5 => ..`.
+///
+/// **Why is this bad?** The literal can be used as the pattern directly, which
+/// is shorter and lets the compiler check the exhaustiveness of the match.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// match x {
+///     n if n == 5 => foo(),
+///     _ => bar(),
+/// }
+/// ```
+/// Could be written as
+/// ```rust
+/// match x {
+///     5 => foo(),
+///     _ => bar(),
+/// }
+/// ```
+declare_lint! {
+    pub LITERAL_GUARD,
+    Warn,
+    "a match arm guard comparing the binding of the pattern to a literal"
+}
+
 #[allow(missing_copy_implementations)]
 pub struct MatchPass;
 
@@ -153,7 +342,13 @@ impl LintPass for MatchPass {
                     MATCH_BOOL,
                     SINGLE_MATCH_ELSE,
                     MATCH_OVERLAPPING_ARM,
-                    MATCH_WILD_ERR_ARM)
+                    MATCH_WILD_ERR_ARM,
+                    INFALLIBLE_DESTRUCTURING_MATCH,
+                    UNREACHABLE_MATCH_ARM,
+                    MANUAL_MAP,
+                    REDUNDANT_PATTERN_BINDING,
+                    MATCH_QUESTION_MARK,
+                    LITERAL_GUARD)
     }
 }
 
@@ -167,6 +362,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for MatchPass {
             check_match_bool(cx, ex, arms, expr);
             check_overlapping_arms(cx, ex, arms);
             check_wild_err_arm(cx, ex, arms);
+            check_infallible_destructuring(cx, ex, arms, expr);
+            check_unreachable_arms(cx, arms);
+            check_manual_map(cx, ex, arms, expr);
+            check_redundant_bindings(cx, arms);
+            check_match_question_mark(cx, ex, arms, expr);
+            check_literal_guards(cx, arms);
         }
         if let ExprMatch(ref ex, ref arms, source) = expr.node {
             check_match_ref_pats(cx, ex, arms, source, expr);
@@ -210,19 +411,18 @@ fn report_single_match_single_pattern(cx: &LateContext, ex: &Expr, arms: &[Arm],
         SINGLE_MATCH
     };
     let els_str = els.map_or(String::new(), |els| format!(" else {}", expr_block(cx, els, None, "..")));
-    span_lint_and_then(cx,
-                       lint,
-                       expr.span,
-                       "you seem to be trying to use match for destructuring a single pattern. Consider using `if \
-                        let`",
-                       |db| {
-        db.span_suggestion(expr.span,
-                           "try this",
-                           format!("if let {} = {} {}{}",
-                                   snippet(cx, arms[0].pats[0].span, ".."),
-                                   snippet(cx, ex.span, ".."),
-                                   expr_block(cx, &arms[0].body, None, ".."),
-                                   els_str));
+    span_lint_and_then_unless_macro(cx,
+                                    lint,
+                                    expr.span,
+                                    "you seem to be trying to use match for destructuring a single pattern. Consider \
+                                     using `if let`",
+                                    |db| {
+        let sugg = format!("if let {} = {} {}{}",
+                           snippet(cx, arms[0].pats[0].span, ".."),
+                           snippet(cx, ex.span, ".."),
+                           expr_block(cx, &arms[0].body, None, ".."),
+                           els_str);
+        db.span_suggestion(expr.span, "try this", reindent_multiline(cx, &sugg, expr.span));
     });
 }
 
@@ -263,14 +463,52 @@ fn check_single_match_opt_like(
     }
 }
 
+fn check_infallible_destructuring(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    if arms.len() != 1 || arms[0].pats.len() != 1 || arms[0].guard.is_some() {
+        return;
+    }
+    match arms[0].pats[0].node {
+        PatKind::TupleStruct(..) |
+        PatKind::Struct(..) => (),
+        _ => return,
+    }
+    if let ty::TyAdt(def, _) = cx.tables.expr_ty(ex).sty {
+        if def.variants.len() != 1 {
+            return;
+        }
+        let binding = format!("let {} = {};", snippet(cx, arms[0].pats[0].span, ".."), snippet(cx, ex.span, ".."));
+        // the arm's body can only replace the `match` if its value is not used
+        let map = &cx.tcx.hir;
+        if let Some(NodeStmt(_)) = map.find(map.get_parent_node(expr.id)) {
+            let body = if let ExprBlock(_) = arms[0].body.node {
+                snippet_block(cx, arms[0].body.span, "..").into_owned()
+            } else {
+                format!("{};", snippet(cx, arms[0].body.span, ".."))
+            };
+            span_lint_and_sugg(cx,
+                               INFALLIBLE_DESTRUCTURING_MATCH,
+                               expr.span,
+                               "this match can never fail, consider using a `let` binding instead",
+                               "try this",
+                               reindent_multiline(cx, &format!("{}\n{}", binding, body), expr.span));
+        } else {
+            span_help_and_lint(cx,
+                               INFALLIBLE_DESTRUCTURING_MATCH,
+                               expr.span,
+                               "this match can never fail, consider using a `let` binding instead",
+                               &format!("try `{}` followed by the body of the arm", binding));
+        }
+    }
+}
+
 fn check_match_bool(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
     // type of expression == bool
     if cx.tables.expr_ty(ex).sty == ty::TyBool {
-        span_lint_and_then(cx,
-                           MATCH_BOOL,
-                           expr.span,
-                           "you seem to be trying to match on a boolean expression",
-                           move |db| {
+        span_lint_and_then_unless_macro(cx,
+                                        MATCH_BOOL,
+                                        expr.span,
+                                        "you seem to be trying to match on a boolean expression",
+                                        move |db| {
             if arms.len() == 2 && arms[0].pats.len() == 1 {
                 // no guards
                 let exprs = if let PatKind::Lit(ref arm_bool) = arms[0].pats[0].node {
@@ -321,10 +559,11 @@ fn check_overlapping_arms(cx: &LateContext, ex: &Expr, arms: &[Arm]) {
         let type_ranges = type_ranges(&ranges);
         if !type_ranges.is_empty() {
             if let Some((start, end)) = overlapping(&type_ranges) {
+                let (lo, hi) = intersection(start, end);
                 span_note_and_lint(cx,
                                    MATCH_OVERLAPPING_ARM,
                                    start.span,
-                                   "some ranges overlap",
+                                   &format!("some ranges overlap on `{}`", range_to_string(lo, hi)),
                                    end.span,
                                    "overlaps with this");
             }
@@ -358,6 +597,261 @@ fn check_wild_err_arm(cx: &LateContext, ex: &Expr, arms: &[Arm]) {
     }
 }
 
+fn check_unreachable_arms(cx: &LateContext, arms: &[Arm]) {
+    for (i, arm) in arms.iter().enumerate() {
+        let previous = &arms[..i];
+        let msg = if previous.iter()
+            .any(|prev| has_true_guard(prev) && prev.pats.iter().any(|pat| !is_refutable(cx, pat))) {
+            "this arm is unreachable because a previous arm matches everything"
+        } else if arm.guard.is_some() && previous.iter().any(|prev| is_same_guarded_arm(cx, prev, arm)) {
+            "this arm is unreachable because a previous arm has the same patterns and guard"
+        } else {
+            continue;
+        };
+        let (first, last) = (arm.pats[0].span, arm.pats[arm.pats.len() - 1].span);
+        let span = if first.ctxt == last.ctxt {
+            Span { hi: last.hi, ..first }
+        } else {
+            first
+        };
+        span_lint(cx, UNREACHABLE_MATCH_ARM, span, msg);
+    }
+}
+
+/// Is the guard of `arm` the literal `true`?
+fn has_true_guard(arm: &Arm) -> bool {
+    arm.guard.as_ref().map_or(false, |guard| match guard.node {
+        ExprLit(ref lit) => lit.node == LitKind::Bool(true),
+        _ => false,
+    })
+}
+
+/// Do `a` and `b` have the same patterns and the same guard?
+fn is_same_guarded_arm(cx: &LateContext, a: &Arm, b: &Arm) -> bool {
+    let eq = SpanlessEq::new(cx);
+    match (&a.guard, &b.guard) {
+        (&Some(ref a_guard), &Some(ref b_guard)) => {
+            eq.eq_expr(a_guard, b_guard) && a.pats.len() == b.pats.len() &&
+            a.pats.iter().zip(&b.pats).all(|(a_pat, b_pat)| eq.eq_pat(a_pat, b_pat))
+        },
+        _ => false,
+    }
+}
+
+fn check_redundant_bindings<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, arms: &'tcx [Arm]) {
+    for arm in arms {
+        let mut bindings = Vec::new();
+        for pat in &arm.pats {
+            pat.walk(|p| {
+                if let PatKind::Binding(_, def_id, ref ident, ref sub) = p.node {
+                    bindings.push((p.span, def_id, ident.node, sub.as_ref().map(|sub| sub.span)));
+                }
+                true
+            });
+        }
+
+        for &(span, _, name, sub_span) in &bindings {
+            let sub_span = match sub_span {
+                Some(sub_span) => sub_span,
+                None => continue,
+            };
+            // with several alternatives, uses resolve to only one of the bindings of that name
+            let used = bindings.iter()
+                .filter(|&&(_, _, other, _)| other == name)
+                .any(|&(_, def_id, _, _)| {
+                    arm.guard.as_ref().map_or(false, |guard| used_in_expr(cx, def_id, guard)) ||
+                    used_in_expr(cx, def_id, &arm.body)
+                });
+            if !used && !name.as_str().starts_with('_') {
+                span_lint_and_sugg(cx,
+                                   REDUNDANT_PATTERN_BINDING,
+                                   span,
+                                   &format!("the binding `{}` is never used", name),
+                                   "remove the binding",
+                                   snippet(cx, sub_span, "..").into_owned());
+            }
+        }
+    }
+}
+
+fn check_manual_map(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return;
+    }
+    if !match_type(cx, cx.tables.expr_ty(ex), &paths::OPTION) {
+        return;
+    }
+
+    let some_arm = if is_none_to_none_arm(cx, &arms[1]) {
+        &arms[0]
+    } else if is_none_to_none_arm(cx, &arms[0]) {
+        &arms[1]
+    } else {
+        return;
+    };
+
+    if_let_chain! {[
+        let PatKind::TupleStruct(ref qpath, ref pats, None) = some_arm.pats[0].node,
+        pats.len() == 1,
+        let PatKind::Binding(BindByValue(_), _, ref ident, None) = pats[0].node,
+        is_option_variant(cx, qpath, some_arm.pats[0].id, &paths::OPTION_SOME),
+        let ExprCall(ref fun, ref args) = remove_blocks(&some_arm.body).node,
+        args.len() == 1,
+        let ExprPath(ref fun_path) = fun.node,
+        is_option_variant(cx, fun_path, fun.id, &paths::OPTION_SOME),
+        !contains_return(&args[0]),
+        // `Some(x) => Some(x)` does not map anything
+        !is_binding_path(&args[0], ident.node)
+    ], {
+        span_lint_and_sugg(cx,
+                           MANUAL_MAP,
+                           expr.span,
+                           "this `match` can be written with `Option::map`",
+                           "try",
+                           format!("{}.map(|{}| {})",
+                                   Sugg::hir(cx, ex, "..").maybe_par(),
+                                   snippet(cx, pats[0].span, ".."),
+                                   snippet(cx, args[0].span, "..")));
+    }}
+}
+
+fn check_literal_guards<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, arms: &'tcx [Arm]) {
+    for arm in arms {
+        if_let_chain! {[
+            arm.pats.len() == 1,
+            let PatKind::Binding(BindByValue(MutImmutable), def_id, ref name, None) = arm.pats[0].node,
+            let Some(ref guard) = arm.guard,
+            let ExprBinary(ref op, ref left, ref right) = guard.node,
+            op.node == BiEq,
+        ], {
+            let lit = if is_binding_path(left, name.node) {
+                right
+            } else if is_binding_path(right, name.node) {
+                left
+            } else {
+                continue;
+            };
+            // float literals in patterns are deprecated, and `String`s can be compared to `&str`s but
+            // not matched against them
+            match lit.node {
+                ExprLit(ref l) if !matches!(l.node, LitKind::Float(..) | LitKind::FloatUnsuffixed(..)) => (),
+                _ => continue,
+            }
+            let parent = cx.tcx.hir.get_parent(arm.body.id);
+            if !same_tys(cx, cx.tables.pat_ty(&arm.pats[0]), cx.tables.expr_ty(lit), parent) {
+                continue;
+            }
+
+            let span = Span { hi: guard.span.hi, ..arm.pats[0].span };
+            let sugg = if used_in_expr(cx, def_id, &arm.body) {
+                format!("{} @ {}", name.node, snippet(cx, lit.span, ".."))
+            } else {
+                snippet(cx, lit.span, "..").into_owned()
+            };
+            span_lint_and_sugg(cx,
+                               LITERAL_GUARD,
+                               span,
+                               "this guard compares the binding to a literal",
+                               "use the literal as the pattern",
+                               sugg);
+        }}
+    }
+}
+
+fn check_match_question_mark(cx: &LateContext, ex: &Expr, arms: &[Arm], expr: &Expr) {
+    if arms.len() != 2 || arms.iter().any(|arm| arm.pats.len() != 1 || arm.guard.is_some()) {
+        return;
+    }
+    if !match_type(cx, cx.tables.expr_ty(ex), &paths::RESULT) ||
+       !match_type(cx, return_ty(cx, cx.tcx.hir.get_parent(expr.id)), &paths::RESULT) {
+        return;
+    }
+
+    let err_arm = if is_ok_to_value_arm(cx, &arms[0]) {
+        &arms[1]
+    } else if is_ok_to_value_arm(cx, &arms[1]) {
+        &arms[0]
+    } else {
+        return;
+    };
+
+    if_let_chain! {[
+        let PatKind::TupleStruct(ref qpath, ref pats, None) = err_arm.pats[0].node,
+        pats.len() == 1,
+        let PatKind::Binding(BindByValue(_), _, ref ident, None) = pats[0].node,
+        is_option_variant(cx, qpath, err_arm.pats[0].id, &paths::RESULT_ERR),
+        let ExprRet(Some(ref ret)) = remove_blocks(&err_arm.body).node,
+        let ExprCall(ref fun, ref args) = ret.node,
+        args.len() == 1,
+        let ExprPath(ref fun_path) = fun.node,
+        is_option_variant(cx, fun_path, fun.id, &paths::RESULT_ERR),
+    ], {
+        let converts = match args[0].node {
+            ExprMethodCall(ref name, _, ref into_args) if name.node == "into" && into_args.len() == 1 => {
+                if is_binding_path(&into_args[0], ident.node) {
+                    true
+                } else {
+                    return;
+                }
+            },
+            _ if is_binding_path(&args[0], ident.node) => false,
+            _ => return,
+        };
+        span_lint_and_then(cx,
+                           MATCH_QUESTION_MARK,
+                           expr.span,
+                           "this `match` can be written with the `?` operator",
+                           |db| {
+            if converts {
+                db.note("the `?` operator converts the error with `From::from` rather than `Into::into`");
+            }
+            db.span_suggestion(expr.span, "try this", format!("{}?", Sugg::hir(cx, ex, "..").maybe_par()));
+        });
+    }}
+}
+
+/// Check whether the arm is `Ok(x) => x`.
+fn is_ok_to_value_arm(cx: &LateContext, arm: &Arm) -> bool {
+    if_let_chain! {[
+        let PatKind::TupleStruct(ref qpath, ref pats, None) = arm.pats[0].node,
+        pats.len() == 1,
+        let PatKind::Binding(BindByValue(_), _, ref ident, None) = pats[0].node,
+        is_option_variant(cx, qpath, arm.pats[0].id, &paths::RESULT_OK),
+    ], {
+        return is_binding_path(remove_blocks(&arm.body), ident.node);
+    }}
+    false
+}
+
+/// Check whether the arm is `None => None`.
+fn is_none_to_none_arm(cx: &LateContext, arm: &Arm) -> bool {
+    if let PatKind::Path(ref qpath) = arm.pats[0].node {
+        if is_option_variant(cx, qpath, arm.pats[0].id, &paths::OPTION_NONE) {
+            let body = remove_blocks(&arm.body);
+            if let ExprPath(ref body_path) = body.node {
+                return is_option_variant(cx, body_path, body.id, &paths::OPTION_NONE);
+            }
+        }
+    }
+    false
+}
+
+fn is_option_variant(cx: &LateContext, qpath: &QPath, id: NodeId, path: &[&str]) -> bool {
+    match cx.tables.qpath_def(qpath, id) {
+        def::Def::Variant(def_id) |
+        def::Def::VariantCtor(def_id, _) => match_def_path(cx.tcx, def_id, path),
+        _ => false,
+    }
+}
+
+fn is_binding_path(expr: &Expr, name: Name) -> bool {
+    if let ExprPath(QPath::Resolved(None, ref path)) = expr.node {
+        path.segments.len() == 1 && path.segments[0].name == name
+    } else {
+        false
+    }
+}
+
 // If the block contains only a `panic!` macro (as expression or statement)
 fn is_panic_block(block: &Block) -> bool {
     match (&block.expr, block.stmts.len(), block.stmts.first()) {
@@ -372,29 +866,33 @@ fn is_panic_block(block: &Block) -> bool {
 }
 
 fn check_match_ref_pats(cx: &LateContext, ex: &Expr, arms: &[Arm], source: MatchSource, expr: &Expr) {
-    if has_only_ref_pats(arms) {
-        if let ExprAddrOf(Mutability::MutImmutable, ref inner) = ex.node {
-            span_lint_and_then(cx,
-                               MATCH_REF_PATS,
-                               expr.span,
-                               "you don't need to add `&` to both the expression and the patterns",
-                               |db| {
-                let inner = Sugg::hir(cx, inner, "..");
-                let template = match_template(expr.span, source, &inner);
-                db.span_suggestion(expr.span, "try", template);
-            });
-        } else {
-            span_lint_and_then(cx,
-                               MATCH_REF_PATS,
-                               expr.span,
-                               "you don't need to add `&` to all patterns",
-                               |db| {
-                let ex = Sugg::hir(cx, ex, "..");
-                let template = match_template(expr.span, source, &ex.deref());
-                db.span_suggestion(expr.span,
-                                   "instead of prefixing all patterns with `&`, you can dereference the expression",
-                                   template);
-            });
+    if let Some(pats_mutbl) = ref_pats_mutability(arms) {
+        match ex.node {
+            ExprAddrOf(mutbl, ref inner) if mutbl == pats_mutbl => {
+                span_lint_and_then(cx,
+                                   MATCH_REF_PATS,
+                                   expr.span,
+                                   "you don't need to add `&` to both the expression and the patterns",
+                                   |db| {
+                    let inner = Sugg::hir(cx, inner, "..");
+                    let template = match_template(expr.span, source, &inner);
+                    db.span_suggestion(expr.span, "try", template);
+                });
+            },
+            ExprAddrOf(..) => (),
+            _ => {
+                span_lint_and_then(cx,
+                                   MATCH_REF_PATS,
+                                   expr.span,
+                                   "you don't need to add `&` to all patterns",
+                                   |db| {
+                    let ex = Sugg::hir(cx, ex, "..");
+                    let template = match_template(expr.span, source, &ex.deref());
+                    db.span_suggestion(expr.span,
+                                       "instead of prefixing all patterns with `&`, you can dereference the expression",
+                                       template);
+                });
+            },
         }
     }
 }
@@ -471,6 +969,43 @@ fn type_ranges(ranges: &[SpannedRange<ConstVal>]) -> TypedRanges {
         .collect()
 }
 
+pub struct UnitArmStyle {
+    prefer_block: bool,
+}
+
+impl UnitArmStyle {
+    pub fn new(style: String) -> UnitArmStyle {
+        UnitArmStyle { prefer_block: style == "{}" }
+    }
+}
+
+impl LintPass for UnitArmStyle {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNIT_ARM_STYLE)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnitArmStyle {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if in_external_macro(cx, expr.span) {
+            return;
+        }
+        if let ExprMatch(_, ref arms, MatchSource::Normal) = expr.node {
+            let (wrong, preferred) = if self.prefer_block { ("()", "{}") } else { ("{}", "()") };
+            for arm in arms {
+                // compare the snippet rather than the HIR so that blocks containing comments are not linted
+                if is_unit_expr(&arm.body) && snippet(cx, arm.body.span, "") == wrong {
+                    span_lint_and_then(cx,
+                                       UNIT_ARM_STYLE,
+                                       arm.body.span,
+                                       &format!("empty match arm body written as `{}`", wrong),
+                                       |db| { db.span_suggestion(arm.body.span, "try this", preferred.to_owned()); });
+                }
+            }
+        }
+    }
+}
+
 fn is_unit_expr(expr: &Expr) -> bool {
     match expr.node {
         ExprTup(ref v) if v.is_empty() => true,
@@ -479,19 +1014,24 @@ fn is_unit_expr(expr: &Expr) -> bool {
     }
 }
 
-fn has_only_ref_pats(arms: &[Arm]) -> bool {
-    let mapped = arms.iter()
-        .flat_map(|a| &a.pats)
-        .map(|p| {
-            match p.node {
-                PatKind::Ref(..) => Some(true),  // &-patterns
-                PatKind::Wild => Some(false),   // an "anything" wildcard is also fine
-                _ => None,                    // any other pattern is not fine
-            }
-        })
-        .collect::<Option<Vec<bool>>>();
-    // look for Some(v) where there's at least one true element
-    mapped.map_or(false, |v| v.iter().any(|el| *el))
+/// Returns the mutability of the `&`-patterns if all the patterns of `arms` are either `&`-patterns
+/// of the same mutability or wildcards, and there is at least one `&`-pattern.
+fn ref_pats_mutability(arms: &[Arm]) -> Option<Mutability> {
+    let mut mutbl = None;
+    for pat in arms.iter().flat_map(|a| &a.pats) {
+        match pat.node {
+            PatKind::Ref(_, pat_mutbl) => {
+                // `&` and `&mut` patterns can't be mixed on the same scrutinee anyway
+                if mutbl.map_or(false, |m| m != pat_mutbl) {
+                    return None;
+                }
+                mutbl = Some(pat_mutbl);
+            },
+            PatKind::Wild => (), // an "anything" wildcard is also fine
+            _ => return None, // any other pattern is not fine
+        }
+    }
+    mutbl
 }
 
 fn match_template(span: Span, source: MatchSource, expr: &Sugg) -> String {
@@ -504,6 +1044,49 @@ fn match_template(span: Span, source: MatchSource, expr: &Sugg) -> String {
     }
 }
 
+/// Get the intersection of two overlapping ranges.
+pub fn intersection<T>(a: &SpannedRange<T>, b: &SpannedRange<T>) -> (T, Bound<T>)
+    where T: Copy + Ord
+{
+    let start = cmp::max(a.node.0, b.node.0);
+    let end = match (a.node.1, b.node.1) {
+        (Bound::Unbounded, end) |
+        (end, Bound::Unbounded) => end,
+        (Bound::Included(a), Bound::Included(b)) => Bound::Included(cmp::min(a, b)),
+        (Bound::Excluded(a), Bound::Excluded(b)) => Bound::Excluded(cmp::min(a, b)),
+        (Bound::Included(included), Bound::Excluded(excluded)) |
+        (Bound::Excluded(excluded), Bound::Included(included)) => {
+            if included < excluded {
+                Bound::Included(included)
+            } else {
+                Bound::Excluded(excluded)
+            }
+        },
+    };
+
+    (start, end)
+}
+
+/// Format a range the way it would be written as a pattern.
+fn range_to_string(start: ConstInt, end: Bound<ConstInt>) -> String {
+    match end {
+        Bound::Included(end) if end == start => int_to_string(start),
+        Bound::Included(end) => format!("{}...{}", int_to_string(start), int_to_string(end)),
+        Bound::Excluded(end) => format!("{}..{}", int_to_string(start), int_to_string(end)),
+        Bound::Unbounded => format!("{}..", int_to_string(start)),
+    }
+}
+
+/// Format an integer without its type suffix.
+#[allow(cast_possible_wrap)]
+fn int_to_string(int: ConstInt) -> String {
+    if int.is_negative() {
+        (int.to_u128_unchecked() as i128).to_string()
+    } else {
+        int.to_u128_unchecked().to_string()
+    }
+}
+
 pub fn overlapping<T>(ranges: &[SpannedRange<T>]) -> Option<(&SpannedRange<T>, &SpannedRange<T>)>
     where T: Copy + Ord
 {
@@ -6,11 +6,13 @@ use rustc::hir::def::Def;
 use rustc_const_eval::ConstContext;
 use std::borrow::Cow;
 use std::fmt;
+use syntax::ast::UintTy;
 use syntax::codemap::Span;
-use utils::{get_trait_def_id, implements_trait, in_external_macro, in_macro, is_copy, match_path, match_trait_method,
-            match_type, method_chain_args, return_ty, same_tys, snippet, span_lint, span_lint_and_then,
-            span_note_and_lint, walk_ptrs_ty, walk_ptrs_ty_depth, last_path_segment, single_segment_path,
-            match_def_path, is_self, is_self_ty, iter_input_pats};
+use utils::{contains_return, get_trait_def_id, implements_trait, in_external_macro, in_macro, is_copy, match_path,
+            match_trait_method, match_type, method_chain_args, return_ty, same_tys, snippet, span_lint,
+            span_lint_and_sugg, span_lint_and_then, span_help_and_lint, span_note_and_lint, walk_ptrs_ty,
+            walk_ptrs_ty_depth, last_path_segment, single_segment_path, match_def_path, is_self, is_self_ty,
+            iter_input_pats, remove_blocks, get_parent_expr, is_integer_literal, SpanlessEq};
 use utils::paths;
 use utils::sugg;
 
@@ -158,7 +160,9 @@ declare_lint! {
 /// **Why is this bad?** Readability, this can be written more concisely as
 /// `_.map_or(_, _)`.
 ///
-/// **Known problems:** None.
+/// **Known problems:** Only `Option`s are linted, `Result` has no `map_or`
+/// method. Note that the default is evaluated eagerly by both `unwrap_or` and
+/// `map_or`, so the suggestion preserves any side effect it has.
 ///
 /// **Example:**
 /// ```rust
@@ -176,7 +180,8 @@ declare_lint! {
 /// **Why is this bad?** Readability, this can be written more concisely as
 /// `_.map_or_else(_, _)`.
 ///
-/// **Known problems:** None.
+/// **Known problems:** Only `Option`s are linted, `Result` has no
+/// `map_or_else` method.
 ///
 /// **Example:**
 /// ```rust
@@ -189,12 +194,57 @@ declare_lint! {
      `map_or_else(g, f)`"
 }
 
+/// **What it does:** Checks for usage of `_.and_then(_)` on an `Option` where
+/// the closure always returns `Some`.
+///
+/// **Why is this bad?** Readability, this can be written more concisely as
+/// `_.map(_)`.
+///
+/// **Known problems:** Only closures which obviously return `Some` are
+/// detected, e.g. a `Some(..)` call, an `if` or `match` whose branches are
+/// all `Some(..)`, or an `_.or(Some(..))` chain.
+///
+/// **Example:**
+/// ```rust
+/// x.and_then(|a| Some(a + 1))
+/// ```
+declare_lint! {
+    pub OPTION_AND_THEN_SOME,
+    Warn,
+    "using `Option.and_then(|x| Some(y))`, which is more succinctly expressed as `map(|x| y)`"
+}
+
+/// **What it does:** Checks for usage of `_.or_else(_)` on a `Result` where
+/// the closure always returns `Ok`.
+///
+/// **Why is this bad?** The error is always recovered from, which is more
+/// clearly expressed with `_.unwrap_or_else(_)`.
+///
+/// **Known problems:** Only closures which obviously return `Ok` are
+/// detected, like for `option_and_then_some`.
+///
+/// **Example:**
+/// ```rust
+/// x.or_else(|_| Ok(0))
+/// ```
+/// Could be written as
+/// ```rust
+/// Ok(x.unwrap_or_else(|_| 0))
+/// ```
+declare_lint! {
+    pub RESULT_OR_ELSE_OK,
+    Warn,
+    "using `Result.or_else(|e| Ok(y))`, which is more clearly expressed as `Ok(unwrap_or_else(|e| y))`"
+}
+
 /// **What it does:** Checks for usage of `_.filter(_).next()`.
 ///
 /// **Why is this bad?** Readability, this can be written more concisely as
-/// `_.find(_)`.
+/// `_.find(_)`, or `_.any(_)` if it is followed by `.is_some()`.
 ///
-/// **Known problems:** None.
+/// **Known problems:** The predicate of `any` takes the items by value rather
+/// than by reference, so the closure may need to be adjusted in the latter
+/// case.
 ///
 /// **Example:**
 /// ```rust
@@ -256,6 +306,7 @@ declare_lint! {
 /// **Example:**
 /// ```rust
 /// name.chars().next() == Some('_')
+/// name.chars().next().unwrap() == '_'
 /// ```
 declare_lint! {
     pub CHARS_NEXT_CMP,
@@ -470,6 +521,30 @@ declare_lint! {
     "using `.get().unwrap()` or `.get_mut().unwrap()` when using `[]` would work instead"
 }
 
+/// **What it does:** Checks for use of `.get(0)` or `.get(x.len() - 1)` on a
+/// slice or `Vec`.
+///
+/// **Why is this bad?** `.first()` and `.last()` say the same thing more
+/// directly, and the latter does not underflow on empty slices.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let first = some_vec.get(0);
+/// let last = some_vec.get(some_vec.len() - 1);
+/// ```
+/// The correct use would be:
+/// ```rust
+/// let first = some_vec.first();
+/// let last = some_vec.last();
+/// ```
+declare_lint! {
+    pub GET_FIRST_LAST,
+    Warn,
+    "using `.get(0)` or `.get(x.len() - 1)` when `.first()` or `.last()` would work instead"
+}
+
 /// **What it does:** Checks for the use of `.extend(s.chars())` where s is a
 /// `&str` or `String`.
 ///
@@ -501,7 +576,8 @@ declare_lint! {
 
 /// **What it does:** Checks for the use of `.cloned().collect()` on slice to create a `Vec`.
 ///
-/// **Why is this bad?** `.to_vec()` is clearer
+/// **Why is this bad?** `.to_vec()` is clearer, as is `.clone()` if the
+/// slice is a `Vec` already.
 ///
 /// **Known problems:** None.
 ///
@@ -521,6 +597,157 @@ declare_lint! {
     "using `.cloned().collect()` on slice to create a `Vec`"
 }
 
+/// **What it does:** Checks for `.collect::<Vec<_>>().len()` on iterators.
+///
+/// **Why is this bad?** The `Vec` is allocated just to be counted, `.count()`
+/// gives the same result without allocating.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let n = v.iter().filter(|x| x.is_some()).collect::<Vec<_>>().len();
+/// ```
+/// The better use would be:
+/// ```rust
+/// let n = v.iter().filter(|x| x.is_some()).count();
+/// ```
+declare_lint! {
+    pub COLLECT_LEN,
+    Warn,
+    "using `.collect::<Vec<_>>().len()` on an iterator, which could be `.count()`"
+}
+
+/// **What it does:** Checks for `_.or(Some(x)).unwrap()` and `_.or(None)` on
+/// `Option`s.
+///
+/// **Why is this bad?** The former is `_.unwrap_or(x)`, the latter does
+/// nothing at all.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let x = opt.or(Some(0)).unwrap();
+/// let y = opt.or(None);
+/// ```
+/// The correct use would be:
+/// ```rust
+/// let x = opt.unwrap_or(0);
+/// let y = opt;
+/// ```
+declare_lint! {
+    pub NEEDLESS_OPTION_OR,
+    Warn,
+    "using `.or(Some(x)).unwrap()` or `.or(None)` on an `Option`"
+}
+
+/// **What it does:** Checks for usage of `.entry(_).or_insert_with(_)` on a
+/// `HashMap` or `BTreeMap` where the given function is equivalent to
+/// `Default::default`, e.g. `Vec::new` or `|| String::new()`.
+///
+/// **Why is this bad?** `.or_default()` is more concise and says what is meant.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// map.entry(key).or_insert_with(Vec::new).push(value);
+/// ```
+/// The correct use would be:
+/// ```rust
+/// map.entry(key).or_default().push(value);
+/// ```
+declare_lint! {
+    pub OR_INSERT_WITH_DEFAULT,
+    Warn,
+    "using `.entry(_).or_insert_with(T::new)` on a map, which can be written as `.or_default()`"
+}
+
+/// **What it does:** Checks for `_.chars().count()` on strings where the
+/// result is compared to a `len()` or used as a capacity.
+///
+/// **Why is this bad?** `chars().count()` is the number of `char`s, while
+/// `len()` and capacities are measured in bytes. Both only agree for ASCII
+/// strings, so mixing them is likely a mistake.
+///
+/// **Known problems:** The `char` count may really be what is meant, in which
+/// case this lint can be allowed.
+///
+/// **Example:**
+/// ```rust
+/// let mut buf = String::with_capacity(s.chars().count());
+/// ```
+declare_lint! {
+    pub CHARS_COUNT,
+    Allow,
+    "using `.chars().count()` on a string as if it were its length in bytes"
+}
+
+/// **What it does:** Checks for `_.as_str()`, `_.as_slice()` or `_.as_ref()`
+/// on a `String` or `Vec` directly followed by a `str` or slice method which is
+/// reachable through `Deref` anyway, like `s.as_str().len()`.
+///
+/// **Why is this bad?** The conversion is redundant and only adds noise.
+///
+/// **Known problems:** Only a few well-known methods are checked.
+///
+/// **Example:**
+/// ```rust
+/// if s.as_str().is_empty() { .. }
+/// ```
+/// The correct use would be:
+/// ```rust
+/// if s.is_empty() { .. }
+/// ```
+declare_lint! {
+    pub NEEDLESS_AS_STR,
+    Warn,
+    "using `.as_str()` or `.as_slice()` before a method which is available through `Deref`"
+}
+
+/// **What it does:** Checks for several `_.and_then(_)` calls in a row on an
+/// `Option` or a `Result`.
+///
+/// **Why is this bad?** Each step of the chain is a separate closure, a single
+/// closure (using `match` or `?` where possible) is often easier to follow.
+///
+/// **Known problems:** Merging the closures is not always more readable, e.g.
+/// when they are named functions.
+///
+/// **Example:**
+/// ```rust
+/// x.and_then(|a| a.checked_add(1)).and_then(|b| b.checked_mul(2))
+/// ```
+declare_lint! {
+    pub CHAINED_AND_THEN,
+    Allow,
+    "using several `.and_then(_)` in a row on an `Option` or a `Result`"
+}
+
+/// **What it does:** Checks for `.into_iter()` calls on arrays, or on
+/// references to arrays like `(&arr).into_iter()`.
+///
+/// **Why is this bad?** Arrays cannot be iterated by value, the call
+/// auto-references the array and yields references to its elements, which is
+/// surprising given the name of the method. `.iter()` makes it explicit.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let values: Vec<&u32> = [1, 2, 3].into_iter().collect();
+/// ```
+/// The correct use would be:
+/// ```rust
+/// let values: Vec<&u32> = [1, 2, 3].iter().collect();
+/// ```
+declare_lint! {
+    pub INTO_ITER_ON_ARRAY,
+    Warn,
+    "using `.into_iter()` on an array, which yields references"
+}
+
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
         lint_array!(OPTION_UNWRAP_USED,
@@ -531,6 +758,8 @@ impl LintPass for Pass {
                     OK_EXPECT,
                     OPTION_MAP_UNWRAP_OR,
                     OPTION_MAP_UNWRAP_OR_ELSE,
+                    OPTION_AND_THEN_SOME,
+                    RESULT_OR_ELSE_OK,
                     OR_FUN_CALL,
                     CHARS_NEXT_CMP,
                     CLONE_ON_COPY,
@@ -544,8 +773,16 @@ impl LintPass for Pass {
                     ITER_NTH,
                     ITER_SKIP_NEXT,
                     GET_UNWRAP,
+                    GET_FIRST_LAST,
                     STRING_EXTEND_CHARS,
-                    ITER_CLONED_COLLECT)
+                    ITER_CLONED_COLLECT,
+                    COLLECT_LEN,
+                    NEEDLESS_OPTION_OR,
+                    OR_INSERT_WITH_DEFAULT,
+                    CHARS_COUNT,
+                    NEEDLESS_AS_STR,
+                    CHAINED_AND_THEN,
+                    INTO_ITER_ON_ARRAY)
     }
 }
 
@@ -560,51 +797,134 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
 
         match expr.node {
             hir::ExprMethodCall(name, _, ref args) => {
-                // Chain calls
-                // GET_UNWRAP needs to be checked before general `UNWRAP` lints
-                if let Some(arglists) = method_chain_args(expr, &["get", "unwrap"]) {
-                    lint_get_unwrap(cx, expr, arglists[0], false);
-                } else if let Some(arglists) = method_chain_args(expr, &["get_mut", "unwrap"]) {
-                    lint_get_unwrap(cx, expr, arglists[0], true);
-                } else if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-                    lint_unwrap(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["ok", "expect"]) {
-                    lint_ok_expect(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or"]) {
-                    lint_map_unwrap_or(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or_else"]) {
-                    lint_map_unwrap_or_else(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "next"]) {
-                    lint_filter_next(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "map"]) {
-                    lint_filter_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "map"]) {
-                    lint_filter_map_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter", "flat_map"]) {
-                    lint_filter_flat_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "flat_map"]) {
-                    lint_filter_map_flat_map(cx, expr, arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["find", "is_some"]) {
-                    lint_search_is_some(cx, expr, "find", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["position", "is_some"]) {
-                    lint_search_is_some(cx, expr, "position", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["rposition", "is_some"]) {
-                    lint_search_is_some(cx, expr, "rposition", arglists[0], arglists[1]);
-                } else if let Some(arglists) = method_chain_args(expr, &["extend"]) {
-                    lint_extend(cx, expr, arglists[0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["unwrap", "as_ptr"]) {
-                    lint_cstring_as_ptr(cx, expr, &arglists[0][0], &arglists[1][0]);
-                } else if let Some(arglists) = method_chain_args(expr, &["iter", "nth"]) {
-                    lint_iter_nth(cx, expr, arglists[0], false);
-                } else if let Some(arglists) = method_chain_args(expr, &["iter_mut", "nth"]) {
-                    lint_iter_nth(cx, expr, arglists[0], true);
-                } else if method_chain_args(expr, &["skip", "next"]).is_some() {
-                    lint_iter_skip_next(cx, expr);
-                } else if let Some(arglists) = method_chain_args(expr, &["cloned", "collect"]) {
-                    lint_iter_cloned_collect(cx, expr, arglists[0]);
+                // Chain calls, dispatched on the name of the last method of the chain so that only the
+                // relevant chains are matched against `expr`
+                match &*name.node.as_str() {
+                    "unwrap" => {
+                        if let Some(arglists) = method_chain_args(expr, &["or", "unwrap"]) {
+                            lint_or_some_unwrap(cx, expr, arglists[0]);
+                        }
+                        // GET_UNWRAP needs to be checked before general `UNWRAP` lints
+                        if let Some(arglists) = method_chain_args(expr, &["get", "unwrap"]) {
+                            lint_get_unwrap(cx, expr, arglists[0], false);
+                        } else if let Some(arglists) = method_chain_args(expr, &["get_mut", "unwrap"]) {
+                            lint_get_unwrap(cx, expr, arglists[0], true);
+                        } else if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
+                            lint_unwrap(cx, expr, arglists[0]);
+                        }
+                    },
+                    "get" => {
+                        if let Some(arglists) = method_chain_args(expr, &["get"]) {
+                            lint_get_first_last(cx, expr, arglists[0]);
+                        }
+                    },
+                    "expect" => {
+                        if let Some(arglists) = method_chain_args(expr, &["ok", "expect"]) {
+                            lint_ok_expect(cx, expr, arglists[0]);
+                        }
+                    },
+                    "unwrap_or" => {
+                        if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or"]) {
+                            lint_map_unwrap_or(cx, expr, arglists[0], arglists[1]);
+                        }
+                    },
+                    "unwrap_or_else" => {
+                        if let Some(arglists) = method_chain_args(expr, &["map", "unwrap_or_else"]) {
+                            lint_map_unwrap_or_else(cx, expr, arglists[0], arglists[1]);
+                        }
+                    },
+                    "and_then" => {
+                        if let Some(arglists) = method_chain_args(expr, &["and_then"]) {
+                            lint_and_then_some(cx, expr, arglists[0]);
+                        }
+                        lint_chained_and_then(cx, expr);
+                    },
+                    "or" => {
+                        if let Some(arglists) = method_chain_args(expr, &["or"]) {
+                            lint_or_none(cx, expr, arglists[0]);
+                        }
+                    },
+                    "or_else" => {
+                        if let Some(arglists) = method_chain_args(expr, &["or_else"]) {
+                            lint_or_else_ok(cx, expr, arglists[0]);
+                        }
+                    },
+                    "next" => {
+                        if let Some(arglists) = method_chain_args(expr, &["filter", "next"]) {
+                            lint_filter_next(cx, expr, arglists[0]);
+                        } else if method_chain_args(expr, &["skip", "next"]).is_some() {
+                            lint_iter_skip_next(cx, expr);
+                        }
+                    },
+                    "map" => {
+                        if let Some(arglists) = method_chain_args(expr, &["filter", "map"]) {
+                            lint_filter_map(cx, expr, arglists[0], arglists[1]);
+                        } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "map"]) {
+                            lint_filter_map_map(cx, expr, arglists[0], arglists[1]);
+                        }
+                    },
+                    "flat_map" => {
+                        if let Some(arglists) = method_chain_args(expr, &["filter", "flat_map"]) {
+                            lint_filter_flat_map(cx, expr, arglists[0], arglists[1]);
+                        } else if let Some(arglists) = method_chain_args(expr, &["filter_map", "flat_map"]) {
+                            lint_filter_map_flat_map(cx, expr, arglists[0], arglists[1]);
+                        }
+                    },
+                    "is_some" => {
+                        for &search_method in &["find", "position", "rposition"] {
+                            if let Some(arglists) = method_chain_args(expr, &[search_method, "is_some"]) {
+                                lint_search_is_some(cx, expr, search_method, arglists[0], arglists[1]);
+                                break;
+                            }
+                        }
+                    },
+                    "extend" => {
+                        if let Some(arglists) = method_chain_args(expr, &["extend"]) {
+                            lint_extend(cx, expr, arglists[0]);
+                        }
+                    },
+                    "as_ptr" => {
+                        if let Some(arglists) = method_chain_args(expr, &["unwrap", "as_ptr"]) {
+                            lint_cstring_as_ptr(cx, expr, &arglists[0][0], &arglists[1][0]);
+                        }
+                    },
+                    "nth" => {
+                        if let Some(arglists) = method_chain_args(expr, &["iter", "nth"]) {
+                            lint_iter_nth(cx, expr, arglists[0], false);
+                        } else if let Some(arglists) = method_chain_args(expr, &["iter_mut", "nth"]) {
+                            lint_iter_nth(cx, expr, arglists[0], true);
+                        }
+                    },
+                    "collect" => {
+                        if let Some(arglists) = method_chain_args(expr, &["cloned", "collect"]) {
+                            lint_iter_cloned_collect(cx, expr, arglists[0]);
+                        }
+                    },
+                    "or_insert_with" => {
+                        if let Some(arglists) = method_chain_args(expr, &["entry", "or_insert_with"]) {
+                            lint_or_insert_with_default(cx, expr, arglists[1]);
+                        }
+                    },
+                    "len" => {
+                        if let Some(arglists) = method_chain_args(expr, &["collect", "len"]) {
+                            lint_collect_len(cx, expr, arglists[0]);
+                        }
+                    },
+                    "count" => {
+                        if let Some(arglists) = method_chain_args(expr, &["chars", "count"]) {
+                            lint_chars_count(cx, expr, arglists[0]);
+                        }
+                    },
+                    "into_iter" => {
+                        if let Some(arglists) = method_chain_args(expr, &["into_iter"]) {
+                            lint_into_iter_on_array(cx, expr, arglists[0]);
+                        }
+                    },
+                    _ => (),
                 }
 
                 lint_or_fun_call(cx, expr, &name.node.as_str(), args);
+                lint_needless_as_str(cx, expr, &name.node.as_str(), args);
 
                 let self_ty = cx.tables.expr_ty_adjusted(&args[0]);
                 if args.len() == 1 && name.node == "clone" {
@@ -623,8 +943,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                 }
             },
             hir::ExprBinary(op, ref lhs, ref rhs) if op.node == hir::BiEq || op.node == hir::BiNe => {
-                if !lint_chars_next(cx, expr, lhs, rhs, op.node == hir::BiEq) {
-                    lint_chars_next(cx, expr, rhs, lhs, op.node == hir::BiEq);
+                let eq = op.node == hir::BiEq;
+                if !lint_chars_next(cx, expr, lhs, rhs, eq) && !lint_chars_next(cx, expr, rhs, lhs, eq) &&
+                   !lint_chars_next_unwrap(cx, expr, lhs, rhs, eq) {
+                    lint_chars_next_unwrap(cx, expr, rhs, lhs, eq);
                 }
             },
             _ => (),
@@ -769,21 +1091,18 @@ fn lint_or_fun_call(cx: &LateContext, expr: &hir::Expr, name: &str, args: &[hir:
             &[(&paths::BTREEMAP_ENTRY, false, &["or_insert"], "with"),
               (&paths::HASHMAP_ENTRY, false, &["or_insert"], "with"),
               (&paths::OPTION, false, &["map_or", "ok_or", "or", "unwrap_or"], "else"),
+              (&paths::OPTION, false, &["get_or_insert"], "with"),
               (&paths::RESULT, true, &["or", "unwrap_or"], "else")];
 
         let self_ty = cx.tables.expr_ty(self_expr);
 
-        let (fn_has_arguments, poss, suffix) = if let Some(&(_, fn_has_arguments, poss, suffix)) =
-            know_types.iter().find(|&&i| match_type(cx, self_ty, i.0)) {
-            (fn_has_arguments, poss, suffix)
+        let (fn_has_arguments, suffix) = if let Some(&(_, fn_has_arguments, _, suffix)) =
+            know_types.iter().find(|&&i| match_type(cx, self_ty, i.0) && i.2.contains(&name)) {
+            (fn_has_arguments, suffix)
         } else {
             return;
         };
 
-        if !poss.contains(&name) {
-            return;
-        }
-
         let sugg: Cow<_> = match (fn_has_arguments, !or_has_args) {
             (true, _) => format!("|_| {}", snippet(cx, arg.span, "..")).into(),
             (false, false) => format!("|| {}", snippet(cx, arg.span, "..")).into(),
@@ -901,15 +1220,238 @@ fn lint_cstring_as_ptr(cx: &LateContext, expr: &hir::Expr, new: &hir::Expr, unwr
     }}
 }
 
+fn lint_collect_len(cx: &LateContext, expr: &hir::Expr, collect_args: &[hir::Expr]) {
+    // `expr` is `_.collect().len()`, the receiver of `len` is the `collect` call
+    let collect_expr = match expr.node {
+        hir::ExprMethodCall(_, _, ref args) => &args[0],
+        _ => return,
+    };
+    if match_trait_method(cx, collect_expr, &paths::ITERATOR) &&
+       match_type(cx, cx.tables.expr_ty(collect_expr), &paths::VEC) {
+        span_lint_and_sugg(cx,
+                           COLLECT_LEN,
+                           expr.span,
+                           "called `.collect::<Vec<_>>().len()` on an iterator, which allocates just to count",
+                           "try this",
+                           format!("{}.count()", snippet(cx, collect_args[0].span, "..")));
+    }
+}
+
+/// lint use of `_.as_str().len()` and the like on `String`s and `Vec`s
+fn lint_needless_as_str(cx: &LateContext, expr: &hir::Expr, name: &str, args: &[hir::Expr]) {
+    if_let_chain! {[
+        let hir::ExprMethodCall(conversion, _, ref conversion_args) = args[0].node,
+        conversion_args.len() == 1,
+        ["as_str", "as_slice", "as_ref"].contains(&&*conversion.node.as_str()),
+    ], {
+        let recv_ty = walk_ptrs_ty(cx.tables.expr_ty(&conversion_args[0]));
+        // `as_ref` could convert to anything, only lint if it results in the `Deref` target
+        let (ty_name, methods): (_, &[_]) = match walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty {
+            ty::TyStr if match_type(cx, recv_ty, &paths::STRING) => ("String", &DEREF_STR_METHODS),
+            ty::TySlice(_) if match_type(cx, recv_ty, &paths::VEC) => ("Vec", &DEREF_SLICE_METHODS),
+            _ => return,
+        };
+        if methods.contains(&name) {
+            span_lint_and_sugg(cx,
+                               NEEDLESS_AS_STR,
+                               args[0].span,
+                               &format!("called `{}()` on a `{}` before `{}()`, which can be called on the `{}` \
+                                         directly",
+                                        conversion.node,
+                                        ty_name,
+                                        name,
+                                        ty_name),
+                               "try this",
+                               snippet(cx, conversion_args[0].span, "_").into_owned());
+        }
+    }}
+}
+
+/// lint use of `or(Some(x)).unwrap()` for `Option`s
+fn lint_or_some_unwrap(cx: &LateContext, expr: &hir::Expr, or_args: &[hir::Expr]) {
+    if_let_chain! {[
+        match_type(cx, cx.tables.expr_ty(&or_args[0]), &paths::OPTION),
+        let hir::ExprCall(ref fun, ref some_args) = or_args[1].node,
+        some_args.len() == 1,
+        let hir::ExprPath(ref qpath) = fun.node,
+        match_def_path(cx.tcx, cx.tables.qpath_def(qpath, fun.id).def_id(), &paths::OPTION_SOME),
+    ], {
+        let default = snippet(cx, some_args[0].span, "..");
+        // don't make a call which was only evaluated for `None` eager
+        let sugg = match some_args[0].node {
+            hir::ExprCall(..) | hir::ExprMethodCall(..) => format!("unwrap_or_else(|| {})", default),
+            _ => format!("unwrap_or({})", default),
+        };
+        span_lint_and_sugg(cx,
+                           NEEDLESS_OPTION_OR,
+                           expr.span,
+                           "called `or(Some(x)).unwrap()` on an `Option`, which is `unwrap_or(x)`",
+                           "try this",
+                           format!("{}.{}", snippet(cx, or_args[0].span, "_"), sugg));
+    }}
+}
+
+/// lint use of `or(None)` for `Option`s
+fn lint_or_none(cx: &LateContext, expr: &hir::Expr, or_args: &[hir::Expr]) {
+    if_let_chain! {[
+        match_type(cx, cx.tables.expr_ty(&or_args[0]), &paths::OPTION),
+        let hir::ExprPath(ref qpath) = or_args[1].node,
+        match_def_path(cx.tcx, cx.tables.qpath_def(qpath, or_args[1].id).def_id(), &paths::OPTION_NONE),
+    ], {
+        span_lint_and_sugg(cx,
+                           NEEDLESS_OPTION_OR,
+                           expr.span,
+                           "called `or(None)` on an `Option`, which does nothing",
+                           "try this",
+                           snippet(cx, or_args[0].span, "_").into_owned());
+    }}
+}
+
 fn lint_iter_cloned_collect(cx: &LateContext, expr: &hir::Expr, iter_args: &[hir::Expr]) {
-    if match_type(cx, cx.tables.expr_ty(expr), &paths::VEC) &&
-       derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_some() {
-        span_lint(cx,
-                  ITER_CLONED_COLLECT,
-                  expr.span,
-                  "called `cloned().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both faster and \
-                   more readable");
+    let ty = cx.tables.expr_ty(expr);
+    if !match_type(cx, ty, &paths::VEC) ||
+       derefs_to_slice(cx, &iter_args[0], cx.tables.expr_ty(&iter_args[0])).is_none() {
+        return;
     }
+
+    if let hir::ExprMethodCall(_, _, ref args) = iter_args[0].node {
+        let recv = sugg::Sugg::hir(cx, &args[0], "..").maybe_par();
+        if walk_ptrs_ty(cx.tables.expr_ty(&args[0])) == ty {
+            span_lint_and_sugg(cx,
+                               ITER_CLONED_COLLECT,
+                               expr.span,
+                               "called `iter().cloned().collect()` on a `Vec` to create the same `Vec`. Calling \
+                                `clone()` is both faster and more readable",
+                               "try this",
+                               format!("{}.clone()", recv));
+        } else {
+            span_lint_and_sugg(cx,
+                               ITER_CLONED_COLLECT,
+                               expr.span,
+                               "called `cloned().collect()` on a slice to create a `Vec`. Calling `to_vec()` is both \
+                                faster and more readable",
+                               "try this",
+                               format!("{}.to_vec()", recv));
+        }
+    }
+}
+
+/// lint use of `entry(_).or_insert_with(T::new)` for `HashMap`s and `BTreeMap`s
+fn lint_or_insert_with_default(cx: &LateContext, expr: &hir::Expr, or_insert_args: &[hir::Expr]) {
+    let entry_ty = cx.tables.expr_ty(&or_insert_args[0]);
+    if !match_type(cx, entry_ty, &paths::HASHMAP_ENTRY) && !match_type(cx, entry_ty, &paths::BTREEMAP_ENTRY) {
+        return;
+    }
+
+    // `or_insert_with(Vec::new)` or `or_insert_with(|| Vec::new())`
+    let fun = match or_insert_args[1].node {
+        hir::ExprClosure(_, ref decl, eid, _) if decl.inputs.is_empty() => {
+            match cx.tcx.hir.body(eid).value.node {
+                hir::ExprCall(ref fun, ref args) if args.is_empty() => &**fun,
+                _ => return,
+            }
+        },
+        hir::ExprPath(_) => &or_insert_args[1],
+        _ => return,
+    };
+
+    if_let_chain! {[
+        let hir::ExprPath(ref qpath) = fun.node,
+        DEFAULT_CONSTRUCTORS.iter().any(|path| match_path(qpath, path)) ||
+            last_path_segment(qpath).name == "default",
+        let Some(default_trait_id) = get_trait_def_id(cx, &paths::DEFAULT_TRAIT),
+        implements_trait(cx, walk_ptrs_ty(cx.tables.expr_ty(expr)), default_trait_id, &[], None),
+    ], {
+        span_lint_and_then(cx,
+                           OR_INSERT_WITH_DEFAULT,
+                           expr.span,
+                           "use of `or_insert_with` with a function equivalent to `Default::default`",
+                           |db| {
+            db.span_suggestion(expr.span,
+                               "try this",
+                               format!("{}.or_default()", snippet(cx, or_insert_args[0].span, "_")));
+        });
+    }}
+}
+
+/// lint use of `chars().count()` where a byte length is expected
+fn lint_chars_count(cx: &LateContext, expr: &hir::Expr, chars_args: &[hir::Expr]) {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(&chars_args[0]));
+    if ty.sty != ty::TyStr && !match_type(cx, ty, &paths::STRING) {
+        return;
+    }
+    let parent = match get_parent_expr(cx, expr) {
+        Some(parent) => parent,
+        None => return,
+    };
+
+    let is_len_call = |e: &hir::Expr| match e.node {
+        hir::ExprMethodCall(ref name, _, ref args) => {
+            name.node == "len" && is_byte_buffer(cx, cx.tables.expr_ty(&args[0]))
+        },
+        _ => false,
+    };
+    let note = match parent.node {
+        hir::ExprBinary(op, ref left, ref right)
+            if op.node.is_comparison() && (is_len_call(left) || is_len_call(right)) => {
+            "it is compared to a `len()`, which is a length in bytes"
+        },
+        hir::ExprCall(ref fun, _) if is_byte_buffer(cx, cx.tables.expr_ty(parent)) => {
+            match fun.node {
+                hir::ExprPath(ref qpath) if last_path_segment(qpath).name == "with_capacity" => {
+                    "it is used as a capacity, which is a length in bytes"
+                },
+                _ => return,
+            }
+        },
+        hir::ExprMethodCall(ref name, _, ref args)
+            if name.node == "reserve" && args[0].id != expr.id && is_byte_buffer(cx, cx.tables.expr_ty(&args[0])) => {
+            "it is used as a capacity, which is a length in bytes"
+        },
+        _ => return,
+    };
+
+    span_note_and_lint(cx,
+                       CHARS_COUNT,
+                       expr.span,
+                       "`chars().count()` is the number of `char`s, not the length in bytes",
+                       expr.span,
+                       &format!("{}; make sure the `char` count is what you want", note));
+}
+
+/// Is `ty` (behind references) a string or a buffer of bytes, ie. something whose length is in bytes?
+fn is_byte_buffer(cx: &LateContext, ty: ty::Ty) -> bool {
+    let ty = walk_ptrs_ty(ty);
+    match ty.sty {
+        ty::TyStr => true,
+        ty::TySlice(inner) |
+        ty::TyArray(inner, _) => inner.sty == ty::TyUint(UintTy::U8),
+        ty::TyAdt(_, substs) if match_type(cx, ty, &paths::VEC) => substs.type_at(0).sty == ty::TyUint(UintTy::U8),
+        _ => match_type(cx, ty, &paths::STRING),
+    }
+}
+
+fn lint_into_iter_on_array(cx: &LateContext, expr: &hir::Expr, into_iter_args: &[hir::Expr]) {
+    let recv = &into_iter_args[0];
+    let array = match cx.tables.expr_ty(recv).sty {
+        ty::TyArray(..) => recv,
+        // `(&arr).into_iter()`, a reference variable is expected to yield references
+        ty::TyRef(_, ty::TypeAndMut { ty: inner, mutbl: hir::MutImmutable })
+            if matches!(inner.sty, ty::TyArray(..)) => {
+            match recv.node {
+                hir::ExprAddrOf(hir::MutImmutable, ref array) => &**array,
+                _ => return,
+            }
+        },
+        _ => return,
+    };
+
+    span_lint_and_sugg(cx,
+                       INTO_ITER_ON_ARRAY,
+                       expr.span,
+                       "this `.into_iter()` call on an array yields references to its elements, not values",
+                       "use `.iter()` to make it explicit",
+                       format!("{}.iter()", sugg::Sugg::hir(cx, array, "..").maybe_par()));
 }
 
 fn lint_iter_nth(cx: &LateContext, expr: &hir::Expr, iter_args: &[hir::Expr], is_mut: bool) {
@@ -968,6 +1510,51 @@ fn lint_get_unwrap(cx: &LateContext, expr: &hir::Expr, get_args: &[hir::Expr], i
     });
 }
 
+fn lint_get_first_last(cx: &LateContext, expr: &hir::Expr, get_args: &[hir::Expr]) {
+    let expr_ty = cx.tables.expr_ty(&get_args[0]);
+    if derefs_to_slice(cx, &get_args[0], expr_ty).is_none() && !match_type(cx, expr_ty, &paths::VEC) {
+        return;
+    }
+    // `.get(0).unwrap()` is linted by `GET_UNWRAP`
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let hir::ExprMethodCall(ref name, _, _) = parent.node {
+            if name.node == "unwrap" {
+                return;
+            }
+        }
+    }
+
+    let method = if is_integer_literal(&get_args[1], 0) {
+        "first"
+    } else if is_len_minus_one(cx, &get_args[0], &get_args[1]) {
+        "last"
+    } else {
+        return;
+    };
+
+    span_lint_and_sugg(cx,
+                       GET_FIRST_LAST,
+                       expr.span,
+                       &format!("accessing the {} element with `.get()`", method),
+                       "try this",
+                       format!("{}.{}()", sugg::Sugg::hir(cx, &get_args[0], "_").maybe_par(), method));
+}
+
+/// Check whether `index` is `recv.len() - 1`.
+fn is_len_minus_one(cx: &LateContext, recv: &hir::Expr, index: &hir::Expr) -> bool {
+    if_let_chain! {[
+        let hir::ExprBinary(op, ref lhs, ref rhs) = index.node,
+        op.node == hir::BiSub,
+        is_integer_literal(rhs, 1),
+        let hir::ExprMethodCall(ref name, _, ref len_args) = lhs.node,
+        name.node == "len",
+        len_args.len() == 1,
+    ], {
+        return SpanlessEq::new(cx).eq_expr(&len_args[0], recv);
+    }}
+    false
+}
+
 fn lint_iter_skip_next(cx: &LateContext, expr: &hir::Expr) {
     // lint if caller of skip is an Iterator
     if match_trait_method(cx, expr, &paths::ITERATOR) {
@@ -1081,6 +1668,136 @@ fn lint_map_unwrap_or(cx: &LateContext, expr: &hir::Expr, map_args: &[hir::Expr]
     }
 }
 
+/// lint use of `and_then(|x| Some(y))` for `Option`s
+fn lint_and_then_some(cx: &LateContext, expr: &hir::Expr, and_then_args: &[hir::Expr]) {
+    if !match_type(cx, cx.tables.expr_ty(&and_then_args[0]), &paths::OPTION) {
+        return;
+    }
+    if let hir::ExprClosure(_, _, eid, _) = and_then_args[1].node {
+        let body = &cx.tcx.hir.body(eid).value;
+        // an early `return None`, possibly from a `?`, anywhere in the closure makes it fallible
+        if !contains_return(body) && is_expr_variant(cx, body, &paths::OPTION_SOME) {
+            span_help_and_lint(cx,
+                               OPTION_AND_THEN_SOME,
+                               expr.span,
+                               "called `and_then(f)` on an Option value with a closure always returning `Some`",
+                               "use `map(f)` instead and remove the `Some` from the closure");
+        }
+    }
+}
+
+/// lint chains of `and_then(_)` calls on `Option`s and `Result`s
+fn lint_chained_and_then(cx: &LateContext, expr: &hir::Expr) {
+    // only lint the whole chain, from its last call
+    if let Some(parent) = get_parent_expr(cx, expr) {
+        if let hir::ExprMethodCall(ref name, _, ref args) = parent.node {
+            if name.node == "and_then" && args[0].id == expr.id {
+                return;
+            }
+        }
+    }
+
+    let mut calls = 0;
+    let mut base = expr;
+    while let Some(arglists) = method_chain_args(base, &["and_then"]) {
+        calls += 1;
+        base = &arglists[0][0];
+    }
+    if calls < 2 {
+        return;
+    }
+
+    let base_ty = cx.tables.expr_ty(base);
+    let ty_name = if match_type(cx, base_ty, &paths::OPTION) {
+        "an Option"
+    } else if match_type(cx, base_ty, &paths::RESULT) {
+        "a Result"
+    } else {
+        return;
+    };
+    span_help_and_lint(cx,
+                       CHAINED_AND_THEN,
+                       expr.span,
+                       &format!("called `and_then(f)` {} times in a row on {} value", calls, ty_name),
+                       "consider merging the closures into a single one");
+}
+
+/// lint use of `or_else(|e| Ok(y))` for `Result`s
+fn lint_or_else_ok(cx: &LateContext, expr: &hir::Expr, or_else_args: &[hir::Expr]) {
+    if !match_type(cx, cx.tables.expr_ty(&or_else_args[0]), &paths::RESULT) {
+        return;
+    }
+    if let hir::ExprClosure(_, _, eid, _) = or_else_args[1].node {
+        let closure = cx.tcx.hir.body(eid);
+        // an early `return Err(..)`, possibly from a `?`, anywhere in the closure makes it fallible
+        if contains_return(&closure.value) || !is_expr_variant(cx, &closure.value, &paths::RESULT_OK) {
+            return;
+        }
+        span_lint_and_then(cx,
+                           RESULT_OR_ELSE_OK,
+                           expr.span,
+                           "called `or_else(f)` on a Result value with a closure always returning `Ok`",
+                           |db| {
+            if_let_chain! {[
+                closure.arguments.len() == 1,
+                let hir::ExprCall(_, ref ok_args) = remove_blocks(&closure.value).node
+            ], {
+                db.span_suggestion(expr.span,
+                                   "try this",
+                                   format!("Ok({}.unwrap_or_else(|{}| {}))",
+                                           snippet(cx, or_else_args[0].span, ".."),
+                                           snippet(cx, closure.arguments[0].pat.span, ".."),
+                                           snippet(cx, ok_args[0].span, "..")));
+                return;
+            }}
+            db.help("use `unwrap_or_else(f)` instead, remove the `Ok` from the closure and wrap the result in `Ok`");
+        });
+    }
+}
+
+/// Check whether the expression always evaluates to the given variant of an `Option` or `Result`
+/// (e.g. `Some(..)` for `paths::OPTION_SOME`). This is conservative, e.g. any block with
+/// statements is rejected. The caller must ensure the expression contains no `return`.
+fn is_expr_variant(cx: &LateContext, expr: &hir::Expr, variant: &[&str]) -> bool {
+    let ty_path = &variant[..variant.len() - 1];
+    match expr.node {
+        hir::ExprCall(ref fun, ref args) if args.len() == 1 => {
+            if let hir::ExprPath(ref qpath) = fun.node {
+                match_def_path(cx.tcx, cx.tables.qpath_def(qpath, fun.id).def_id(), variant)
+            } else {
+                false
+            }
+        },
+        hir::ExprBlock(ref block) => is_block_variant(cx, block, variant),
+        hir::ExprIf(_, ref then, Some(ref els)) => {
+            is_block_variant(cx, then, variant) && is_expr_variant(cx, els, variant)
+        },
+        hir::ExprMatch(_, ref arms, hir::MatchSource::Normal) => {
+            arms.iter().all(|arm| is_expr_variant(cx, &arm.body, variant))
+        },
+        hir::ExprMethodCall(name, _, ref args) if args.len() == 2 &&
+                                                 match_type(cx, cx.tables.expr_ty(&args[0]), ty_path) => {
+            match &*name.node.as_str() {
+                // `_.or(Some(..))` and `_.or_else(|| Some(..))`
+                "or" => is_expr_variant(cx, &args[1], variant),
+                "or_else" => {
+                    if let hir::ExprClosure(_, _, eid, _) = args[1].node {
+                        is_expr_variant(cx, &cx.tcx.hir.body(eid).value, variant)
+                    } else {
+                        false
+                    }
+                },
+                _ => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn is_block_variant(cx: &LateContext, block: &hir::Block, variant: &[&str]) -> bool {
+    block.stmts.is_empty() && block.expr.as_ref().map_or(false, |e| is_expr_variant(cx, e, variant))
+}
+
 /// lint use of `map().unwrap_or_else()` for `Option`s
 fn lint_map_unwrap_or_else(cx: &LateContext, expr: &hir::Expr, map_args: &[hir::Expr], unwrap_args: &[hir::Expr]) {
     // lint if the caller of `map()` is an `Option`
@@ -1118,13 +1835,27 @@ fn lint_filter_next(cx: &LateContext, expr: &hir::Expr, filter_args: &[hir::Expr
                    `.find(p)` instead.";
         let filter_snippet = snippet(cx, filter_args[1].span, "..");
         if filter_snippet.lines().count() <= 1 {
-            // add note if not multi-line
-            span_note_and_lint(cx,
+            // `.filter(p).next().is_some()` is `.any(p)`
+            if_let_chain! {[
+                let Some(parent) = get_parent_expr(cx, expr),
+                let hir::ExprMethodCall(ref name, _, ref args) = parent.node,
+                name.node == "is_some" && args.len() == 1
+            ], {
+                // the predicate of `filter` takes the items by reference, the one of `any` by value
+                span_help_and_lint(cx,
+                                   FILTER_NEXT,
+                                   parent.span,
+                                   "called `filter(p).next().is_some()` on an `Iterator`. This is more succinctly \
+                                    expressed by calling `.any(p)` instead.",
+                                   "use `any` with the predicate adjusted to take the items by value");
+                return;
+            }}
+            span_lint_and_sugg(cx,
                                FILTER_NEXT,
                                expr.span,
                                msg,
-                               expr.span,
-                               &format!("replace `filter({0}).next()` with `find({0})`", filter_snippet));
+                               "try this",
+                               format!("{}.find({})", snippet(cx, filter_args[0].span, ".."), filter_snippet));
         } else {
             span_lint(cx, FILTER_NEXT, expr.span, msg);
         }
@@ -1237,6 +1968,30 @@ fn lint_chars_next(cx: &LateContext, expr: &hir::Expr, chain: &hir::Expr, other:
     false
 }
 
+/// lint for `s.chars().next().unwrap() == c`, which unlike `starts_with` panics on empty strings
+fn lint_chars_next_unwrap(cx: &LateContext, expr: &hir::Expr, chain: &hir::Expr, other: &hir::Expr, eq: bool) -> bool {
+    if_let_chain! {[
+        let Some(args) = method_chain_args(chain, &["chars", "next", "unwrap"]),
+        cx.tables.expr_ty(other).sty == ty::TyChar,
+        walk_ptrs_ty(cx.tables.expr_ty_adjusted(&args[0][0])).sty == ty::TyStr,
+    ], {
+        span_help_and_lint(cx,
+                           CHARS_NEXT_CMP,
+                           expr.span,
+                           "you should use the `starts_with` method",
+                           &format!("consider using `{}{}.starts_with({})`, note that it returns `{}` instead of \
+                                     panicking on an empty string",
+                                    if eq { "" } else { "!" },
+                                    snippet(cx, args[0][0].span, "_"),
+                                    snippet(cx, other.span, "_"),
+                                    !eq));
+
+        return true;
+    }}
+
+    false
+}
+
 /// lint for length-1 `str`s for methods in `PATTERN_METHODS`
 fn lint_single_char_pattern(cx: &LateContext, expr: &hir::Expr, arg: &hir::Expr) {
     if let Ok(ConstVal::Str(r)) = ConstContext::with_tables(cx.tcx, cx.tables).eval(arg) {
@@ -1321,6 +2076,26 @@ const TRAIT_METHODS: [(&'static str, usize, SelfKind, OutType, &'static str); 30
     ("sub", 2, SelfKind::Value, OutType::Any, "std::ops::Sub"),
 ];
 
+/// Constructors which are known to be equivalent to `Default::default`.
+#[cfg_attr(rustfmt, rustfmt_skip)]
+const DEFAULT_CONSTRUCTORS: [&'static [&'static str]; 7] = [
+    &["BTreeMap", "new"],
+    &["BTreeSet", "new"],
+    &["HashMap", "new"],
+    &["HashSet", "new"],
+    &["String", "new"],
+    &["Vec", "new"],
+    &["VecDeque", "new"],
+];
+
+/// `str` methods which are checked by `NEEDLESS_AS_STR`.
+const DEREF_STR_METHODS: [&'static str; 9] =
+    ["bytes", "chars", "contains", "ends_with", "is_empty", "len", "lines", "starts_with", "trim"];
+
+/// Slice methods which are checked by `NEEDLESS_AS_STR`.
+const DEREF_SLICE_METHODS: [&'static str; 8] =
+    ["contains", "ends_with", "first", "is_empty", "iter", "last", "len", "starts_with"];
+
 #[cfg_attr(rustfmt, rustfmt_skip)]
 const PATTERN_METHODS: [(&'static str, usize); 17] = [
     ("contains", 1),
@@ -8,7 +8,9 @@ use rustc_const_eval::ConstContext;
 use rustc_const_math::ConstFloat;
 use syntax::codemap::{Span, Spanned, ExpnFormat};
 use utils::{get_item_name, get_parent_expr, implements_trait, in_macro, is_integer_literal, match_path, snippet,
-            span_lint, span_lint_and_then, walk_ptrs_ty, last_path_segment, iter_input_pats, in_constant};
+            span_lint, span_lint_and_then, walk_ptrs_ty, last_path_segment, iter_input_pats, in_constant,
+            match_trait_method};
+use utils::{higher, paths, sugg};
 use utils::sugg::Sugg;
 use syntax::ast::LitKind;
 
@@ -297,8 +299,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
                     if let ExprPath(QPath::Resolved(_, ref path)) = right.node {
                         check_nan(cx, path, expr);
                     }
-                    check_to_owned(cx, left, right, true, cmp.span);
-                    check_to_owned(cx, right, left, false, cmp.span)
+                    check_to_owned(cx, expr, left, right, *cmp);
                 }
                 if (op == BiEq || op == BiNe) && (is_float(cx, left) || is_float(cx, right)) {
                     if is_allowed(cx, left) || is_allowed(cx, right) {
@@ -422,66 +423,95 @@ fn is_float(cx: &LateContext, expr: &Expr) -> bool {
     matches!(walk_ptrs_ty(cx.tables.expr_ty(expr)).sty, ty::TyFloat(_))
 }
 
-fn check_to_owned(cx: &LateContext, expr: &Expr, other: &Expr, left: bool, op: Span) {
-    let (arg_ty, snip) = match expr.node {
+fn check_to_owned(cx: &LateContext, expr: &Expr, left: &Expr, right: &Expr, op: BinOp) {
+    let partial_eq_trait_id = match cx.tcx.lang_items.eq_trait() {
+        Some(id) => id,
+        None => return,
+    };
+    let comparable = |left: &Expr, right: &Expr| {
+        implements_trait(cx, cx.tables.expr_ty(left), partial_eq_trait_id, &[cx.tables.expr_ty(right)], None)
+    };
+
+    match (owned_arg(cx, left), owned_arg(cx, right)) {
+        // both sides are converted, compare the borrowed forms directly if possible
+        (Some(left_arg), Some(right_arg)) if comparable(left_arg, right_arg) => {
+            lint_to_owned(cx, expr, expr.span, left_arg, right_arg, op);
+        },
+        (left_arg, right_arg) => {
+            if let Some(left_arg) = left_arg {
+                if comparable(left_arg, right) {
+                    lint_to_owned(cx, expr, left.span, left_arg, right, op);
+                }
+            }
+            if let Some(right_arg) = right_arg {
+                if comparable(left, right_arg) {
+                    lint_to_owned(cx, expr, right.span, left, right_arg, op);
+                }
+            }
+        },
+    }
+}
+
+/// If `expr` creates an owned value from another one (e.g. `x.to_owned()`,
+/// `slice.to_vec()` or `String::from(x)`), return the expression it was created from.
+fn owned_arg<'e>(cx: &LateContext, expr: &'e Expr) -> Option<&'e Expr> {
+    match expr.node {
         ExprMethodCall(Spanned { node: ref name, .. }, _, ref args) if args.len() == 1 => {
             let name = name.as_str();
-            if name == "to_string" || name == "to_owned" && is_str_arg(cx, args) {
-                (cx.tables.expr_ty(&args[0]), snippet(cx, args[0].span, ".."))
+            let is_conversion = match &*name {
+                "to_string" => match_trait_method(cx, expr, &paths::TO_STRING),
+                "to_owned" => {
+                    match_trait_method(cx, expr, &paths::TO_OWNED) && (is_str_arg(cx, args) || is_slice_arg(cx, args))
+                },
+                "to_vec" => is_slice_arg(cx, args),
+                _ => false,
+            };
+            if is_conversion {
+                Some(&args[0])
             } else {
-                return;
+                None
             }
         },
         ExprCall(ref path, ref v) if v.len() == 1 => {
             if let ExprPath(ref path) = path.node {
                 if match_path(path, &["String", "from_str"]) || match_path(path, &["String", "from"]) {
-                    (cx.tables.expr_ty(&v[0]), snippet(cx, v[0].span, ".."))
+                    Some(&v[0])
                 } else {
-                    return;
+                    None
                 }
             } else {
-                return;
+                None
             }
         },
-        _ => return,
-    };
-
-    let other_ty = cx.tables.expr_ty(other);
-    let partial_eq_trait_id = match cx.tcx.lang_items.eq_trait() {
-        Some(id) => id,
-        None => return,
-    };
-
-    if !implements_trait(cx, arg_ty, partial_eq_trait_id, &[other_ty], None) {
-        return;
-    }
-
-    if left {
-        span_lint(cx,
-                  CMP_OWNED,
-                  expr.span,
-                  &format!("this creates an owned instance just for comparison. Consider using `{} {} {}` to \
-                            compare without allocation",
-                           snip,
-                           snippet(cx, op, "=="),
-                           snippet(cx, other.span, "..")));
-    } else {
-        span_lint(cx,
-                  CMP_OWNED,
-                  expr.span,
-                  &format!("this creates an owned instance just for comparison. Consider using `{} {} {}` to \
-                            compare without allocation",
-                           snippet(cx, other.span, ".."),
-                           snippet(cx, op, "=="),
-                           snip));
+        _ => None,
     }
+}
 
+fn lint_to_owned(cx: &LateContext, expr: &Expr, span: Span, left: &Expr, right: &Expr, op: BinOp) {
+    span_lint_and_then(cx,
+                       CMP_OWNED,
+                       span,
+                       &format!("this creates an owned instance just for comparison. Consider using `{} {} {}` to \
+                                 compare without allocation",
+                                snippet(cx, left.span, ".."),
+                                snippet(cx, op.span, "=="),
+                                snippet(cx, right.span, "..")),
+                       |db| {
+        let sugg = sugg::make_binop(higher::binop(op.node),
+                                    &Sugg::hir(cx, left, ".."),
+                                    &Sugg::hir(cx, right, ".."));
+        db.span_suggestion(expr.span, "try this", sugg.to_string());
+    });
 }
 
 fn is_str_arg(cx: &LateContext, args: &[Expr]) -> bool {
     args.len() == 1 && matches!(walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty, ty::TyStr)
 }
 
+fn is_slice_arg(cx: &LateContext, args: &[Expr]) -> bool {
+    args.len() == 1 && matches!(walk_ptrs_ty(cx.tables.expr_ty(&args[0])).sty, ty::TySlice(_))
+}
+
 /// Heuristic to see if an expression is used. Should be compatible with `unused_variables`'s idea
 /// of what it means for an expression to be "used".
 fn is_used(cx: &LateContext, expr: &Expr) -> bool {
@@ -5,12 +5,13 @@
 use rustc::lint::*;
 use rustc::hir::*;
 use syntax::ast::LitKind;
-use syntax::codemap::Spanned;
+use syntax::codemap::{Span, Spanned};
 use utils::{span_lint, span_lint_and_then, snippet};
 use utils::sugg::Sugg;
 
 /// **What it does:** Checks for expressions of the form `if c { true } else { false }`
-/// (or vice versa) and suggest using the condition directly.
+/// (or vice versa), as well as `if c { return true; } return false;`, and suggest
+/// using the condition directly.
 ///
 /// **Why is this bad?** Redundant code.
 ///
@@ -30,8 +31,9 @@ declare_lint! {
      `if p { true } else { false }`"
 }
 
-/// **What it does:** Checks for expressions of the form `x == true` (or vice
-/// versa) and suggest using the variable directly.
+/// **What it does:** Checks for expressions of the form `x == true`, `x != false`
+/// (or vice versa) and suggest using the variable directly, or its negation for
+/// `x == false` and `x != true`.
 ///
 /// **Why is this bad?** Unnecessary code.
 ///
@@ -61,20 +63,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
         use self::Expression::*;
         if let ExprIf(ref pred, ref then_block, Some(ref else_expr)) = e.node {
             let reduce = |ret, not| {
-                let snip = Sugg::hir(cx, pred, "<predicate>");
-                let snip = if not { !snip } else { snip };
-
-                let hint = if ret {
-                    format!("return {}", snip)
-                } else {
-                    snip.to_string()
-                };
-
-                span_lint_and_then(cx,
-                                   NEEDLESS_BOOL,
-                                   e.span,
-                                   "this if-then-else expression returns a bool literal",
-                                   |db| { db.span_suggestion(e.span, "you can reduce it to", hint); });
+                lint_reducible(cx, pred, e.span, ret, not, "this if-then-else expression returns a bool literal")
             };
             if let ExprBlock(ref then_block) = then_block.node {
                 match (fetch_bool_block(then_block), fetch_bool_expr(else_expr)) {
@@ -103,6 +92,56 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBool {
             }
         }
     }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        // `if c { return true; } return false;`
+        let exprs = block.stmts
+            .iter()
+            .map(|stmt| match stmt.node {
+                StmtExpr(ref e, _) | StmtSemi(ref e, _) => Some(&**e),
+                StmtDecl(..) => None,
+            })
+            .chain(Some(block.expr.as_ref().map(|e| &**e)))
+            .collect::<Vec<_>>();
+
+        for pair in exprs.windows(2) {
+            if_let_chain! {[
+                let Some(if_expr) = pair[0],
+                let Some(ret_expr) = pair[1],
+                let ExprIf(ref pred, ref then_block, None) = if_expr.node,
+                let ExprBlock(ref then_block) = then_block.node,
+                let Expression::RetBool(then_value) = fetch_bool_block(then_block),
+                let Expression::RetBool(ret_value) = fetch_bool_expr(ret_expr),
+                then_value != ret_value,
+                if_expr.span.ctxt == ret_expr.span.ctxt
+            ], {
+                let span = Span { hi: ret_expr.span.hi, ..if_expr.span };
+                lint_reducible(cx,
+                               pred,
+                               span,
+                               true,
+                               !then_value,
+                               "this `if` and the following `return` return bool literals");
+            }}
+        }
+    }
+}
+
+fn lint_reducible(cx: &LateContext, pred: &Expr, span: Span, ret: bool, not: bool, msg: &str) {
+    let snip = Sugg::hir(cx, pred, "<predicate>");
+    let snip = if not { !snip } else { snip };
+
+    let hint = if ret {
+        format!("return {}", snip)
+    } else {
+        snip.to_string()
+    };
+
+    span_lint_and_then(cx,
+                       NEEDLESS_BOOL,
+                       span,
+                       msg,
+                       |db| { db.span_suggestion(span, "you can reduce it to", hint); });
 }
 
 #[derive(Copy,Clone)]
@@ -117,46 +156,34 @@ impl LintPass for BoolComparison {
 impl<'a, 'tcx> LateLintPass<'a, 'tcx> for BoolComparison {
     fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
         use self::Expression::*;
-        if let ExprBinary(Spanned { node: BiEq, .. }, ref left_side, ref right_side) = e.node {
-            match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
-                (Bool(true), Other) => {
-                    let hint = snippet(cx, right_side.span, "..").into_owned();
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against true are unnecessary",
-                                       |db| { db.span_suggestion(e.span, "try simplifying it as shown:", hint); });
-                },
-                (Other, Bool(true)) => {
-                    let hint = snippet(cx, left_side.span, "..").into_owned();
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against true are unnecessary",
-                                       |db| { db.span_suggestion(e.span, "try simplifying it as shown:", hint); });
-                },
-                (Bool(false), Other) => {
-                    let hint = Sugg::hir(cx, right_side, "..");
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against false can be replaced by a negation",
-                                       |db| {
-                        db.span_suggestion(e.span, "try simplifying it as shown:", (!hint).to_string());
-                    });
-                },
-                (Other, Bool(false)) => {
-                    let hint = Sugg::hir(cx, left_side, "..");
-                    span_lint_and_then(cx,
-                                       BOOL_COMPARISON,
-                                       e.span,
-                                       "equality checks against false can be replaced by a negation",
-                                       |db| {
-                        db.span_suggestion(e.span, "try simplifying it as shown:", (!hint).to_string());
-                    });
-                },
-                _ => (),
-            }
+        if let ExprBinary(Spanned { node, .. }, ref left_side, ref right_side) = e.node {
+            let eq = match node {
+                BiEq => true,
+                BiNe => false,
+                _ => return,
+            };
+            let (value, other) = match (fetch_bool_expr(left_side), fetch_bool_expr(right_side)) {
+                (Bool(value), Other) => (value, right_side),
+                (Other, Bool(value)) => (value, left_side),
+                _ => return,
+            };
+            // `x == true` and `x != false` are just `x`, `x == false` and `x != true` are `!x`
+            let msg = match (eq, value) {
+                (true, true) => "equality checks against true are unnecessary",
+                (false, false) => "inequality checks against false are unnecessary",
+                (true, false) => "equality checks against false can be replaced by a negation",
+                (false, true) => "inequality checks against true can be replaced by a negation",
+            };
+            let hint = if eq == value {
+                snippet(cx, other.span, "..").into_owned()
+            } else {
+                (!Sugg::hir(cx, other, "..")).to_string()
+            };
+            span_lint_and_then(cx,
+                               BOOL_COMPARISON,
+                               e.span,
+                               msg,
+                               |db| { db.span_suggestion(e.span, "try simplifying it as shown:", hint); });
         }
     }
 }
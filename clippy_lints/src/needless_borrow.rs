@@ -1,11 +1,11 @@
-//! Checks for needless address of operations (`&`)
+//! Checks for needless address of operations (`&`) and reborrows (`&*`)
 //!
 //! This lint is **warn** by default
 
 use rustc::lint::*;
-use rustc::hir::{ExprAddrOf, Expr, MutImmutable, Pat, PatKind, BindingMode};
+use rustc::hir::{ExprAddrOf, ExprUnary, Expr, MutImmutable, Pat, PatKind, BindingMode, UnDeref};
 use rustc::ty;
-use utils::{span_lint, in_macro};
+use utils::{span_lint, span_lint_and_sugg, snippet, in_macro};
 
 /// **What it does:** Checks for address of operations (`&`) that are going to
 /// be dereferenced immediately by the compiler.
@@ -25,12 +25,32 @@ declare_lint! {
     "taking a reference that is going to be automatically dereferenced"
 }
 
+/// **What it does:** Checks for `&*x` where `x` is already a shared
+/// reference.
+///
+/// **Why is this bad?** The reborrow yields a reference of the same type, so
+/// it is the same as `x` itself.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// fn foo(s: &str) -> usize {
+///     bar(&*s)
+/// }
+/// ```
+declare_lint! {
+    pub BORROW_DEREF_REF,
+    Warn,
+    "borrowing the dereference of a shared reference, i.e. `&*x` where `x: &T`"
+}
+
 #[derive(Copy,Clone)]
 pub struct NeedlessBorrow;
 
 impl LintPass for NeedlessBorrow {
     fn get_lints(&self) -> LintArray {
-        lint_array!(NEEDLESS_BORROW)
+        lint_array!(NEEDLESS_BORROW, BORROW_DEREF_REF)
     }
 }
 
@@ -53,6 +73,20 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessBorrow {
                 }
             }
         }
+        if_let_chain! {[
+            let ExprAddrOf(MutImmutable, ref deref) = e.node,
+            let ExprUnary(UnDeref, ref inner) = deref.node,
+            // `&*x` on a `&mut T` or a `Box<T>` is not the same as `x`
+            let ty::TyRef(_, ref tam) = cx.tables.expr_ty(inner).sty,
+            tam.mutbl == MutImmutable,
+        ], {
+            span_lint_and_sugg(cx,
+                               BORROW_DEREF_REF,
+                               e.span,
+                               "this borrows the dereference of a shared reference, which is the reference itself",
+                               "try this",
+                               snippet(cx, inner.span, "_").into_owned());
+        }}
     }
     fn check_pat(&mut self, cx: &LateContext<'a, 'tcx>, pat: &'tcx Pat) {
         if in_macro(pat.span) {
@@ -9,8 +9,8 @@ use rustc::middle::mem_categorization as mc;
 use syntax::ast::NodeId;
 use syntax_pos::Span;
 use syntax::errors::DiagnosticBuilder;
-use utils::{in_macro, is_self, is_copy, implements_trait, get_trait_def_id, match_type, snippet, span_lint_and_then,
-            multispan_sugg, paths};
+use utils::{in_macro, is_self, is_copy, get_trait_def_id, match_type, snippet, span_lint_and_then,
+            multispan_sugg, paths, ImplementsTraitCache};
 use std::collections::{HashSet, HashMap};
 
 /// **What it does:** Checks for functions taking arguments by value, but not consuming them in its
@@ -102,6 +102,9 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessPassByValue {
         let fn_sig = cx.tcx.item_type(fn_def_id).fn_sig();
         let fn_sig = cx.tcx.liberate_late_bound_regions(param_env.free_id_outlive, &fn_sig);
 
+        // arguments often share their types
+        let trait_cache = ImplementsTraitCache::default();
+
         for ((input, &ty), arg) in decl.inputs.iter().zip(fn_sig.inputs()).zip(&body.arguments) {
 
             // Determines whether `ty` implements `Borrow<U>` (U != ty) specifically.
@@ -119,8 +122,8 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NeedlessPassByValue {
                 !is_self(arg),
                 !ty.is_mutable_pointer(),
                 !is_copy(cx, ty, node_id),
-                !implements_trait(cx, ty, fn_trait, &[], Some(node_id)),
-                !implements_trait(cx, ty, asref_trait, &[], Some(node_id)),
+                !trait_cache.implements_trait(cx, ty, fn_trait, &[], Some(node_id)),
+                !trait_cache.implements_trait(cx, ty, asref_trait, &[], Some(node_id)),
                 !implements_borrow_trait,
 
                 let PatKind::Binding(mode, defid, ..) = arg.pat.node,
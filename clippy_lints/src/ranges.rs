@@ -1,7 +1,8 @@
 use rustc::lint::*;
 use rustc::hir::*;
 use syntax::codemap::Spanned;
-use utils::{is_integer_literal, match_type, paths, snippet, span_lint};
+use utils::{is_integer_literal, match_type, paths, snippet, span_lint, span_lint_and_sugg, in_macro, SpanlessEq};
+use utils::comparisons::{normalize_comparison, Rel};
 use utils::higher;
 
 /// **What it does:** Checks for iterating over ranges with a `.step_by(0)`,
@@ -37,12 +38,35 @@ declare_lint! {
     "zipping iterator with a range when `enumerate()` would do"
 }
 
+/// **What it does:** Checks for bounds checks like `x >= a && x < b` which
+/// could be written with `Range::contains`.
+///
+/// **Why is this bad?** `(a..b).contains(&x)` states the intent more clearly
+/// and avoids repeating `x`.
+///
+/// **Known problems:** `contains` requires a recent standard library, hence
+/// this lint is `Allow` by default.
+///
+/// **Example:**
+/// ```rust
+/// x >= 5 && x < 10
+/// ```
+/// could be written as
+/// ```rust
+/// (5..10).contains(&x)
+/// ```
+declare_lint! {
+    pub MANUAL_RANGE_CONTAINS,
+    Allow,
+    "manually reimplementing `Range::contains`"
+}
+
 #[derive(Copy,Clone)]
 pub struct StepByZero;
 
 impl LintPass for StepByZero {
     fn get_lints(&self) -> LintArray {
-        lint_array!(RANGE_STEP_BY_ZERO, RANGE_ZIP_WITH_LEN)
+        lint_array!(RANGE_STEP_BY_ZERO, RANGE_ZIP_WITH_LEN, MANUAL_RANGE_CONTAINS)
     }
 }
 
@@ -84,6 +108,12 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StepByZero {
                 }}
             }
         }
+
+        if let ExprBinary(Spanned { node: BiAnd, .. }, ref l, ref r) = expr.node {
+            if !in_macro(expr.span) {
+                check_manual_contains(cx, expr, l, r);
+            }
+        }
     }
 }
 
@@ -96,3 +126,37 @@ fn has_step_by(cx: &LateContext, expr: &Expr) -> bool {
     match_type(cx, ty, &paths::RANGE) || match_type(cx, ty, &paths::RANGE_FROM) ||
     match_type(cx, ty, &paths::RANGE_INCLUSIVE)
 }
+
+/// Check for `x >= lo && x < hi` or `x >= lo && x <= hi`, in any order.
+fn check_manual_contains(cx: &LateContext, expr: &Expr, l: &Expr, r: &Expr) {
+    let (l, r) = match (normalize_bound(l), normalize_bound(r)) {
+        (Some(l), Some(r)) => (l, r),
+        _ => return,
+    };
+
+    for &((lower_rel, lo, x), (upper_rel, y, hi)) in &[(l, r), (r, l)] {
+        if lower_rel == Rel::Le && (upper_rel == Rel::Lt || upper_rel == Rel::Le) &&
+           SpanlessEq::new(cx).eq_expr(x, y) {
+            let dots = if upper_rel == Rel::Lt { ".." } else { "..." };
+            span_lint_and_sugg(cx,
+                               MANUAL_RANGE_CONTAINS,
+                               expr.span,
+                               "manual `Range::contains` implementation",
+                               "use",
+                               format!("({}{}{}).contains(&{})",
+                                       snippet(cx, lo.span, "_"),
+                                       dots,
+                                       snippet(cx, hi.span, "_"),
+                                       snippet(cx, x.span, "_")));
+            return;
+        }
+    }
+}
+
+fn normalize_bound(expr: &Expr) -> Option<(Rel, &Expr, &Expr)> {
+    if let ExprBinary(op, ref lhs, ref rhs) = expr.node {
+        normalize_comparison(op.node, lhs, rhs)
+    } else {
+        None
+    }
+}
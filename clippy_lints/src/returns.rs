@@ -3,9 +3,10 @@ use syntax::ast;
 use syntax::codemap::{Span, Spanned};
 use syntax::visit::FnKind;
 
-use utils::{span_note_and_lint, span_lint_and_then, snippet_opt, match_path_ast, in_external_macro};
+use utils::{span_note_and_lint, span_lint_and_then, snippet_opt, match_path_ast, in_macro, in_external_macro};
 
-/// **What it does:** Checks for return statements at the end of a block.
+/// **What it does:** Checks for return statements at the end of a block,
+/// including the branches of a final `if`, `if let` or `match`.
 ///
 /// **Why is this bad?** Removing the `return` and semicolon will make the code
 /// more rusty.
@@ -74,7 +75,8 @@ impl ReturnPass {
             // an if/if let expr, check both exprs
             // note, if without else is going to be a type checking error anyways
             // (except for unit type functions) so we don't match it
-            ast::ExprKind::If(_, ref ifblock, Some(ref elsexpr)) => {
+            ast::ExprKind::If(_, ref ifblock, Some(ref elsexpr)) |
+            ast::ExprKind::IfLet(_, _, ref ifblock, Some(ref elsexpr)) => {
                 self.check_block_return(cx, ifblock);
                 self.check_final_expr(cx, elsexpr, None);
             },
@@ -89,7 +91,8 @@ impl ReturnPass {
     }
 
     fn emit_return_lint(&mut self, cx: &EarlyContext, ret_span: Span, inner_span: Span) {
-        if in_external_macro(cx, inner_span) {
+        // the `return` can't be removed if a macro wrote it
+        if in_macro(ret_span) || in_external_macro(cx, inner_span) {
             return;
         }
         span_lint_and_then(cx,
@@ -1,6 +1,7 @@
 use rustc::lint::*;
 use rustc::hir::*;
-use utils::{is_direct_expn_of, implements_trait, span_lint};
+use syntax::codemap::Span;
+use utils::{is_direct_expn_of, implements_trait, snippet, span_lint_and_sugg};
 
 /// **What it does:** Checks for `assert!(x == y)` or `assert!(x != y)` which can be better written
 /// using `assert_eq` or `assert_ne` if `x` and `y` implement `Debug` trait.
@@ -36,7 +37,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ShouldAssertEq {
             let ExprIf(ref cond, ..) = e.node,
             let ExprUnary(UnOp::UnNot, ref cond) = cond.node,
             let ExprBinary(ref binop, ref expr1, ref expr2) = cond.node,
-            is_direct_expn_of(e.span, "assert").is_some(),
+            let Some(call_site) = is_direct_expn_of(e.span, "assert"),
             let Some(debug_trait) = cx.tcx.lang_items.debug_trait(),
         ], {
             let sugg = match binop.node {
@@ -52,7 +53,26 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ShouldAssertEq {
 
             if implements_trait(cx, ty1, debug_trait, &[], Some(parent)) &&
                 implements_trait(cx, ty2, debug_trait, &[], Some(parent)) {
-                span_lint(cx, SHOULD_ASSERT_EQ, e.span, &format!("use `{}` for better reporting", sugg));
+                // keep the custom message of `assert!(x == y, "..", ..)`, i.e. everything between the
+                // condition and the closing delimiter, and the `;` a statement macro's call site includes
+                let rest = snippet(cx, Span { lo: cond.span.hi, ..call_site }, "");
+                let (rest, semi) = if rest.ends_with(';') {
+                    (&rest[..rest.len() - 1], ";")
+                } else {
+                    (&*rest, "")
+                };
+                let message = if rest.is_empty() { "" } else { &rest[..rest.len() - 1] };
+                span_lint_and_sugg(cx,
+                                   SHOULD_ASSERT_EQ,
+                                   call_site,
+                                   &format!("use `{}` for better reporting", sugg),
+                                   "try",
+                                   format!("{}!({}, {}{}){}",
+                                           sugg,
+                                           snippet(cx, expr1.span, ".."),
+                                           snippet(cx, expr2.span, ".."),
+                                           message,
+                                           semi));
             }
         }}
     }
@@ -0,0 +1,93 @@
+use rustc::hir::*;
+use rustc::lint::*;
+use syntax::ast::Name;
+use utils::{match_trait_method, match_type, paths, span_help_and_lint};
+
+/// **What it does:** Checks for `Vec`s which are sorted with `sort` right
+/// after being collected from an iterator.
+///
+/// **Why is this bad?** Freshly collected elements rarely need to keep their
+/// original relative order when they compare equal. The unstable sort is
+/// faster and does not allocate.
+///
+/// **Known problems:** The stable sort is needed if equal elements can be
+/// told apart and their order matters. Sorts by a key or a comparison
+/// function, where this is common, are not linted. `sort_unstable` is not
+/// stable yet, so this lint only fires in crates enabling
+/// `#![feature(sort_unstable)]`.
+///
+/// **Example:**
+/// ```rust
+/// let mut v: Vec<_> = it.collect();
+/// v.sort();
+/// ```
+/// Could be written:
+/// ```rust
+/// let mut v: Vec<_> = it.collect();
+/// v.sort_unstable();
+/// ```
+declare_lint! {
+    pub SORT_AFTER_COLLECT,
+    Allow,
+    "sorting a `Vec` with the stable `sort` right after collecting it"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(SORT_AFTER_COLLECT)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for window in block.stmts.windows(2) {
+            if_let_chain! {[
+                let StmtDecl(ref decl, _) = window[0].node,
+                let DeclLocal(ref local) = decl.node,
+                let PatKind::Binding(BindByValue(MutMutable), _, ref name, None) = local.pat.node,
+                let Some(ref init) = local.init,
+                is_vec_collect(cx, init),
+                let StmtSemi(ref sort, _) = window[1].node,
+                is_sort_call(sort, name.node),
+                has_sort_unstable(cx),
+            ], {
+                span_help_and_lint(cx,
+                                   SORT_AFTER_COLLECT,
+                                   sort.span,
+                                   "this `Vec` is sorted with `sort` right after being collected",
+                                   &format!("if the order of equal elements does not matter, consider using \
+                                             `{}.sort_unstable()`",
+                                            name.node));
+            }}
+        }
+    }
+}
+
+/// Is `expr` a call to `Iterator::collect` into a `Vec`?
+fn is_vec_collect(cx: &LateContext, expr: &Expr) -> bool {
+    if let ExprMethodCall(ref method, _, ref args) = expr.node {
+        return method.node == "collect" && args.len() == 1 && match_trait_method(cx, expr, &paths::ITERATOR) &&
+               match_type(cx, cx.tables.expr_ty(expr), &paths::VEC);
+    }
+    false
+}
+
+/// Does the crate enable the `sort_unstable` library feature?
+fn has_sort_unstable(cx: &LateContext) -> bool {
+    cx.sess().features.borrow().declared_lib_features.iter().any(|&(name, _)| name == "sort_unstable")
+}
+
+/// Is `expr` a call to `name.sort()`?
+fn is_sort_call(expr: &Expr, name: Name) -> bool {
+    if_let_chain! {[
+        let ExprMethodCall(ref method, _, ref args) = expr.node,
+        method.node == "sort",
+        let ExprPath(QPath::Resolved(None, ref path)) = args[0].node,
+    ], {
+        return path.segments.len() == 1 && path.segments[0].name == name;
+    }}
+    false
+}
@@ -1,8 +1,10 @@
 use rustc::hir::*;
+use rustc::hir::intravisit::{Visitor, walk_block, walk_expr, NestedVisitorMap};
 use rustc::lint::*;
+use rustc::ty;
 use syntax::codemap::Spanned;
 use utils::SpanlessEq;
-use utils::{match_type, paths, span_lint, span_lint_and_then, walk_ptrs_ty, get_parent_expr};
+use utils::{higher, in_macro, match_type, paths, span_lint, span_lint_and_then, walk_ptrs_ty, get_parent_expr};
 
 /// **What it does:** Checks for string appends of the form `x = x + y` (without
 /// `let`!).
@@ -70,6 +72,29 @@ declare_lint! {
     "calling `as_bytes` on a string literal instead of using a byte string literal"
 }
 
+/// **What it does:** Checks for slicing a string by the length of a prefix
+/// right after checking it with `starts_with`, e.g. `if s.starts_with(p) {
+/// &s[p.len()..] }`.
+///
+/// **Why is this bad?** The check and the slicing have to be kept in sync by
+/// hand: changing the prefix in one place but not the other silently slices at
+/// the wrong position (or panics on a char boundary).
+///
+/// **Known problems:** There is no method in `std` to do both at once, the
+/// best fix is a small helper function returning the stripped `Option<&str>`.
+///
+/// **Example:**
+/// ```rust
+/// if s.starts_with(prefix) {
+///     handle(&s[prefix.len()..]);
+/// }
+/// ```
+declare_lint! {
+    pub MANUAL_STRIP,
+    Warn,
+    "slicing a string by the length of a prefix just checked with `starts_with`"
+}
+
 #[derive(Copy, Clone)]
 pub struct StringAdd;
 
@@ -163,3 +188,80 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for StringLitAsBytes {
         }
     }
 }
+
+#[derive(Copy, Clone)]
+pub struct ManualStrip;
+
+impl LintPass for ManualStrip {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MANUAL_STRIP)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for ManualStrip {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, e: &'tcx Expr) {
+        if_let_chain!{[
+            let ExprIf(ref cond, ref then, _) = e.node,
+            !in_macro(e.span),
+            let ExprMethodCall(ref name, _, ref args) = cond.node,
+            name.node == "starts_with",
+            args.len() == 2,
+            is_str(cx, &args[0]),
+        ], {
+            let mut visitor = PrefixSliceVisitor {
+                cx: cx,
+                string: &args[0],
+                prefix: &args[1],
+                slices: vec![],
+            };
+            walk_block(&mut visitor, then);
+
+            for slice in visitor.slices {
+                span_lint_and_then(cx,
+                                   MANUAL_STRIP,
+                                   slice.span,
+                                   "slicing by the length of a prefix checked with `starts_with`",
+                                   |db| {
+                    db.span_help(cond.span,
+                                 "the prefix is checked here, consider a helper function doing both the check \
+                                  and the slicing so they can't get out of sync");
+                });
+            }
+        }}
+    }
+}
+
+fn is_str(cx: &LateContext, e: &Expr) -> bool {
+    let ty = walk_ptrs_ty(cx.tables.expr_ty(e));
+    ty.sty == ty::TyStr || match_type(cx, ty, &paths::STRING)
+}
+
+/// Collects the `string[prefix.len()..]` expressions.
+struct PrefixSliceVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    string: &'tcx Expr,
+    prefix: &'tcx Expr,
+    slices: Vec<&'tcx Expr>,
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for PrefixSliceVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if_let_chain!{[
+            let ExprIndex(ref indexed, ref index) = expr.node,
+            let Some(higher::Range { start: Some(start), end: None, .. }) = higher::range(index),
+            let ExprMethodCall(ref name, _, ref args) = start.node,
+            name.node == "len",
+            SpanlessEq::new(self.cx).eq_expr(indexed, self.string),
+            SpanlessEq::new(self.cx).eq_expr(&args[0], self.prefix),
+        ], {
+            self.slices.push(expr);
+            return;
+        }}
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -1,5 +1,6 @@
 use reexport::*;
 use rustc::hir::*;
+use rustc::hir::def_id::DefId;
 use rustc::hir::intravisit::{FnKind, Visitor, walk_ty, NestedVisitorMap};
 use rustc::lint::*;
 use rustc::ty;
@@ -35,6 +36,28 @@ declare_lint! {
     "usage of `Box<Vec<T>>`, vector elements are already on the heap"
 }
 
+/// **What it does:** Checks for use of `Box<String>` or `Rc<Box<_>>` anywhere
+/// in the code.
+///
+/// **Why is this bad?** `String` already keeps its contents on the heap and
+/// `Rc` allocates its value on the heap, the `Box` only adds another level of
+/// indirection.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// struct X {
+///     name: Box<String>,
+///     shared: Rc<Box<Foo>>,
+/// }
+/// ```
+declare_lint! {
+    pub REDUNDANT_ALLOCATION,
+    Warn,
+    "usage of `Box<String>` or `Rc<Box<T>>`, which add a needless level of indirection"
+}
+
 /// **What it does:** Checks for usage of any `LinkedList`, suggesting to use a
 /// `Vec` or a `VecDeque` (formerly called `RingBuf`).
 ///
@@ -66,7 +89,7 @@ declare_lint! {
 
 impl LintPass for TypePass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(BOX_VEC, LINKEDLIST)
+        lint_array!(BOX_VEC, REDUNDANT_ALLOCATION, LINKEDLIST)
     }
 }
 
@@ -115,22 +138,32 @@ fn check_ty(cx: &LateContext, ast_ty: &Ty) {
             let def = cx.tables.qpath_def(qpath, ast_ty.id);
             if let Some(def_id) = opt_def_id(def) {
                 if Some(def_id) == cx.tcx.lang_items.owned_box() {
-                    let last = last_path_segment(qpath);
-                    if_let_chain! {[
-                        let PathParameters::AngleBracketedParameters(ref ag) = last.parameters,
-                        let Some(vec) = ag.types.get(0),
-                        let TyPath(ref qpath) = vec.node,
-                        let def::Def::Struct(..) = cx.tables.qpath_def(qpath, vec.id),
-                        let Some(did) = opt_def_id(cx.tables.qpath_def(qpath, vec.id)),
-                        match_def_path(cx.tcx, did, &paths::VEC),
-                    ], {
+                    let inner = inner_struct_def_id(cx, qpath);
+                    if inner.map_or(false, |did| match_def_path(cx.tcx, did, &paths::VEC)) {
                         span_help_and_lint(cx,
                                            BOX_VEC,
                                            ast_ty.span,
                                            "you seem to be trying to use `Box<Vec<T>>`. Consider using just `Vec<T>`",
                                            "`Vec<T>` is already on the heap, `Box<Vec<T>>` makes an extra allocation.");
                         return; // don't recurse into the type
-                    }}
+                    } else if inner.map_or(false, |did| match_def_path(cx.tcx, did, &paths::STRING)) {
+                        span_help_and_lint(cx,
+                                           REDUNDANT_ALLOCATION,
+                                           ast_ty.span,
+                                           "usage of `Box<String>`. Consider using just `String`",
+                                           "`String` is already on the heap, `Box<String>` makes an extra allocation.");
+                        return; // don't recurse into the type
+                    }
+                } else if match_def_path(cx.tcx, def_id, &paths::RC) {
+                    if inner_struct_def_id(cx, qpath) == cx.tcx.lang_items.owned_box() && !boxes_unsized_ty(cx, qpath) {
+                        span_help_and_lint(cx,
+                                           REDUNDANT_ALLOCATION,
+                                           ast_ty.span,
+                                           "usage of `Rc<Box<T>>`. Consider using just `Rc<T>`",
+                                           "`Rc<T>` already puts its value on the heap, `Rc<Box<T>>` makes an extra \
+                                            allocation.");
+                        return; // don't recurse into the type
+                    }
                 } else if match_def_path(cx.tcx, def_id, &paths::LINKED_LIST) {
                     span_help_and_lint(cx,
                                        LINKEDLIST,
@@ -174,6 +207,49 @@ fn check_ty(cx: &LateContext, ast_ty: &Ty) {
     }
 }
 
+/// Return the `DefId` of the struct given as the first type parameter of `qpath`, eg. `Vec` in
+/// `Box<Vec<T>>`.
+fn first_ty_param(qpath: &QPath) -> Option<&Ty> {
+    if let PathParameters::AngleBracketedParameters(ref ag) = last_path_segment(qpath).parameters {
+        ag.types.get(0).map(|ty| &**ty)
+    } else {
+        None
+    }
+}
+
+fn inner_struct_def_id(cx: &LateContext, qpath: &QPath) -> Option<DefId> {
+    if_let_chain! {[
+        let Some(ty) = first_ty_param(qpath),
+        let TyPath(ref qpath) = ty.node,
+        let def::Def::Struct(did) = cx.tables.qpath_def(qpath, ty.id),
+    ], {
+        return Some(did);
+    }}
+    None
+}
+
+/// Is the first type parameter of `qpath` a `Box` of an unsized type, e.g. `Rc<Box<Trait>>`? Such
+/// a `Box` makes the `Rc` a thin pointer, so it is not redundant.
+fn boxes_unsized_ty(cx: &LateContext, qpath: &QPath) -> bool {
+    if_let_chain! {[
+        let Some(boxed) = first_ty_param(qpath),
+        let TyPath(ref boxed_qpath) = boxed.node,
+        let Some(inner) = first_ty_param(boxed_qpath),
+    ], {
+        return match inner.node {
+            TySlice(..) | TyTraitObject(..) => true,
+            TyPath(ref inner_qpath) => {
+                match cx.tables.qpath_def(inner_qpath, inner.id) {
+                    def::Def::PrimTy(PrimTy::TyStr) | def::Def::Trait(..) => true,
+                    _ => false,
+                }
+            },
+            _ => false,
+        };
+    }}
+    false
+}
+
 #[allow(missing_copy_implementations)]
 pub struct LetPass;
 
@@ -0,0 +1,149 @@
+use rustc::hir::*;
+use rustc::hir::intravisit::{Visitor, walk_block, walk_expr, NestedVisitorMap};
+use rustc::lint::*;
+use rustc::ty;
+use syntax::codemap::Span;
+use utils::{in_macro, match_type, method_chain_args, paths, snippet, span_lint_and_then, SpanlessEq};
+
+/// **What it does:** Checks for calls of `unwrap()` on an `Option` or a
+/// `Result` in a branch where its variant was just checked with `is_some()`,
+/// `is_ok()`, `is_none()` or `is_err()`.
+///
+/// **Why is this bad?** The check and the `unwrap()` are redundant and the
+/// `unwrap()` reads like it could panic, `if let` says the same thing only
+/// once.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// if option.is_some() {
+///     do_something_with(option.unwrap())
+/// }
+/// ```
+/// Could be written:
+/// ```rust
+/// if let Some(value) = option {
+///     do_something_with(value)
+/// }
+/// ```
+declare_lint! {
+    pub UNNECESSARY_UNWRAP,
+    Warn,
+    "checks for calls of `unwrap()` on an `Option` or a `Result` whose variant was just checked"
+}
+
+#[derive(Copy, Clone)]
+pub struct Pass;
+
+impl LintPass for Pass {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(UNNECESSARY_UNWRAP)
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &'tcx Expr) {
+        if let ExprIf(ref cond, ref then, ref els) = expr.node {
+            if in_macro(expr.span) {
+                return;
+            }
+
+            // (checking method, variant for the `if let`, whether `unwrap` can't fail in the `then` branch)
+            for &(method, variant, in_then) in &[("is_some", "Some", true),
+                                                 ("is_ok", "Ok", true),
+                                                 ("is_none", "Some", false),
+                                                 ("is_err", "Ok", false)] {
+                if let Some(args) = method_chain_args(cond, &[method]) {
+                    let checked = &args[0][0];
+                    let ty = cx.tables.expr_ty(checked);
+                    if !match_type(cx, ty, &paths::OPTION) && !match_type(cx, ty, &paths::RESULT) {
+                        return;
+                    }
+
+                    let mut visitor = UnwrapVisitor {
+                        cx: cx,
+                        checked: checked,
+                        mutated: false,
+                        unwraps: vec![],
+                    };
+                    if in_then {
+                        walk_block(&mut visitor, then);
+                    } else if let Some(ref els) = *els {
+                        walk_expr(&mut visitor, els);
+                    }
+
+                    for unwrap in visitor.unwraps {
+                        span_lint_and_then(cx,
+                                           UNNECESSARY_UNWRAP,
+                                           unwrap,
+                                           &format!("called `unwrap()` on `{}` after checking its variant with `{}`",
+                                                    snippet(cx, checked.span, ".."),
+                                                    method),
+                                           |db| {
+                            db.span_help(cond.span,
+                                         &format!("try `if let {}(..) = {}` instead of this check",
+                                                  variant,
+                                                  snippet(cx, checked.span, "..")));
+                        });
+                    }
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Collects the calls of `unwrap()` on `checked` until `checked` might have been mutated.
+struct UnwrapVisitor<'a, 'tcx: 'a> {
+    cx: &'a LateContext<'a, 'tcx>,
+    checked: &'tcx Expr,
+    mutated: bool,
+    unwraps: Vec<Span>,
+}
+
+impl<'a, 'tcx: 'a> UnwrapVisitor<'a, 'tcx> {
+    fn is_checked(&self, expr: &Expr) -> bool {
+        SpanlessEq::new(self.cx).ignore_fn().eq_expr(self.checked, expr)
+    }
+}
+
+impl<'a, 'tcx: 'a> Visitor<'tcx> for UnwrapVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if self.mutated {
+            return;
+        }
+
+        match expr.node {
+            ExprAssign(ref lhs, ref rhs) |
+            ExprAssignOp(_, ref lhs, ref rhs) if self.is_checked(lhs) => {
+                self.visit_expr(rhs);
+                self.mutated = true;
+                return;
+            },
+            ExprAddrOf(MutMutable, ref inner) if self.is_checked(inner) => {
+                self.mutated = true;
+                return;
+            },
+            ExprMethodCall(ref name, _, ref args) if self.is_checked(&args[0]) => {
+                if name.node == "unwrap" {
+                    self.unwraps.push(expr.span);
+                    return;
+                }
+                // methods taking `&mut self`, eg. `take`
+                if let ty::TyRef(_, ty::TypeAndMut { mutbl: MutMutable, .. }) =
+                       self.cx.tables.expr_ty_adjusted(&args[0]).sty {
+                    self.mutated = true;
+                    return;
+                }
+            },
+            _ => (),
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
@@ -2,7 +2,7 @@
 
 #![deny(missing_docs_in_private_items)]
 
-use std::{env, fmt, fs, io, path};
+use std::{env, fmt, fs, io, mem, path};
 use std::io::Read;
 use syntax::{ast, codemap};
 use toml;
@@ -45,6 +45,13 @@ pub enum Error {
          &'static str),
     /// There is an unknown key is the file.
     UnknownKey(String),
+    /// A key has a value of the right type which is not one of the accepted values.
+    Value(/// The name of the key.
+          &'static str,
+          /// The accepted values.
+          &'static [&'static str],
+          /// The value we got instead.
+          String),
 }
 
 impl fmt::Display for Error {
@@ -68,6 +75,9 @@ impl fmt::Display for Error {
                 write!(f, "`{}` is expected to be a `{}` but is a `{}`", key, expected, got)
             },
             Error::UnknownKey(ref key) => write!(f, "unknown key `{}`", key),
+            Error::Value(key, expected, ref got) => {
+                write!(f, "`{}` is expected to be one of `{}` but is `{}`", key, expected.join("`, `"), got)
+            },
         }
     }
 }
@@ -180,6 +190,8 @@ define_Conf! {
     ] => Vec<String>),
     /// Lint: TOO_MANY_ARGUMENTS. The maximum number of argument a function or method can have
     ("too-many-arguments-threshold", too_many_arguments_threshold, 7 => u64),
+    /// Lint: TOO_MANY_LINES. The maximum number of lines a function body can have
+    ("too-many-lines-threshold", too_many_lines_threshold, 100 => u64),
     /// Lint: TYPE_COMPLEXITY. The maximum complexity a type can have
     ("type-complexity-threshold", type_complexity_threshold, 250 => u64),
     /// Lint: MANY_SINGLE_CHAR_NAMES. The maximum number of single char bindings a scope may have
@@ -190,6 +202,14 @@ define_Conf! {
     ("enum-variant-name-threshold", enum_variant_name_threshold, 3 => u64),
     /// Lint: LARGE_ENUM_VARIANT. The maximum size of a emum's variant to avoid box suggestion
     ("enum-variant-size-threshold", enum_variant_size_threshold, 200 => u64),
+    /// Lint: EXPLICIT_COUNTER_LOOP. The maximum number of uses of a counter in the loop body besides its increment
+    ("explicit-counter-max-uses", explicit_counter_max_uses, ::std::u64::MAX => u64),
+    /// Lint: UNIT_ARM_STYLE. The preferred form of an empty match arm body, either `"()"` or `"{}"`
+    ("unit-arm-style", unit_arm_style, "()".to_owned() => String),
+    /// Lint: UNREADABLE_LITERAL. The maximum number of digits a literal can have without `_` separators
+    ("unreadable-literal-threshold", unreadable_literal_threshold, 5 => u64),
+    /// Lints and lint groups which are reported as errors instead of warnings, unless they are allowed
+    ("deny-lints", deny_lints, Vec::<&str>::new() => Vec<String>),
 }
 
 /// Search for the configuration file.
@@ -268,5 +288,11 @@ pub fn read(path: Option<&path::Path>) -> (Conf, Vec<Error>) {
         }
     }
 
+    const UNIT_ARM_STYLES: &'static [&'static str] = &["()", "{}"];
+    if !UNIT_ARM_STYLES.contains(&conf.unit_arm_style.as_str()) {
+        let style = mem::replace(&mut conf.unit_arm_style, UNIT_ARM_STYLES[0].to_owned());
+        errors.push(Error::Value("unit-arm-style", UNIT_ARM_STYLES, style));
+    }
+
     (conf, errors)
 }
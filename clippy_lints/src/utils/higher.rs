@@ -5,6 +5,7 @@
 use rustc::hir;
 use rustc::lint::LateContext;
 use syntax::ast;
+use syntax::codemap::Span;
 use utils::{is_expn_of, match_path, match_def_path, resolve_node, paths};
 
 /// Convert a hir binary operator to the corresponding `ast` type.
@@ -143,6 +144,20 @@ pub fn for_loop(expr: &hir::Expr) -> Option<(&hir::Pat, &hir::Expr, &hir::Expr)>
     None
 }
 
+/// Returns the pre-expansion span if this comes from an expansion of `assert!`, `assert_eq!`,
+/// `assert_ne!` or their `debug_` counterparts.
+pub fn is_assert_macro(span: Span) -> Option<Span> {
+    /// The macros recognized by `is_assert_macro`.
+    const ASSERT_MACROS: [&'static str; 6] = ["assert",
+                                               "assert_eq",
+                                               "assert_ne",
+                                               "debug_assert",
+                                               "debug_assert_eq",
+                                               "debug_assert_ne"];
+
+    ASSERT_MACROS.iter().filter_map(|name| is_expn_of(span, name)).next()
+}
+
 /// Represent the pre-expansion arguments of a `vec!` invocation.
 pub enum VecArgs<'a> {
     /// `vec![elem; len]`
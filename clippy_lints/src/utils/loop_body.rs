@@ -0,0 +1,94 @@
+//! Walk the body of a loop while keeping track of the loop each `break` and `continue` refers
+//! to.
+
+#![deny(missing_docs_in_private_items)]
+
+use rustc::hir::*;
+use rustc::hir::intravisit::{Visitor, walk_expr, NestedVisitorMap};
+use syntax::ast::Name;
+
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+/// The loop a `break` or `continue` refers to, as seen from the walked loop body.
+pub enum LoopTarget {
+    /// The loop whose body is walked.
+    Current,
+    /// A loop nested in the walked body, `Inner(1)` being a loop directly in the body.
+    Inner(usize),
+    /// A labeled loop around the walked one.
+    Outer,
+}
+
+/// The callbacks of `walk_loop_body`. All methods do nothing by default.
+pub trait LoopBodyVisitor<'tcx> {
+    /// Called on each `break`, with the loop it exits.
+    fn visit_break(&mut self, _expr: &'tcx Expr, _target: LoopTarget) {}
+    /// Called on each `continue`, with the loop it continues.
+    fn visit_continue(&mut self, _expr: &'tcx Expr, _target: LoopTarget) {}
+}
+
+/// Walk `body`, the body of a loop labeled `label`, calling `visitor` on each `break` and
+/// `continue`. Closures are not walked as they cannot refer to the loops around them.
+pub fn walk_loop_body<'tcx, V: LoopBodyVisitor<'tcx>>(body: &'tcx Block, label: Option<Name>, visitor: &mut V) {
+    let mut walker = LoopBodyWalker {
+        labels: vec![label],
+        visitor: visitor,
+    };
+    walker.visit_block(body);
+}
+
+/// The `Visitor` used by `walk_loop_body`.
+struct LoopBodyWalker<'v, V: 'v> {
+    /// The labels of the loops around the visited expression, the walked loop first.
+    labels: Vec<Option<Name>>,
+    /// The user's callbacks.
+    visitor: &'v mut V,
+}
+
+impl<'v, V> LoopBodyWalker<'v, V> {
+    /// Find the loop a `break` or `continue` refers to.
+    fn target(&self, dest: Destination) -> LoopTarget {
+        let depth = if let Some(ident) = dest.ident {
+            match self.labels.iter().rposition(|&label| label == Some(ident.node.name)) {
+                Some(depth) => depth,
+                None => return LoopTarget::Outer,
+            }
+        } else {
+            self.labels.len() - 1
+        };
+
+        if depth == 0 {
+            LoopTarget::Current
+        } else {
+            LoopTarget::Inner(depth)
+        }
+    }
+}
+
+impl<'v, 'tcx, V: LoopBodyVisitor<'tcx>> Visitor<'tcx> for LoopBodyWalker<'v, V> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        match expr.node {
+            ExprBreak(dest, _) => {
+                let target = self.target(dest);
+                self.visitor.visit_break(expr, target);
+            },
+            ExprAgain(dest) => {
+                let target = self.target(dest);
+                self.visitor.visit_continue(expr, target);
+            },
+            ExprLoop(_, label, _) |
+            ExprWhile(_, _, label) => {
+                self.labels.push(label.map(|label| label.node));
+                walk_expr(self, expr);
+                self.labels.pop();
+                return;
+            },
+            _ => (),
+        }
+
+        walk_expr(self, expr);
+    }
+
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::None
+    }
+}
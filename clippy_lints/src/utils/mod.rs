@@ -2,8 +2,9 @@ use reexport::*;
 use rustc::hir::*;
 use rustc::hir::def_id::{DefId, CRATE_DEF_INDEX};
 use rustc::hir::def::Def;
+use rustc::hir::intravisit::{self, Visitor, NestedVisitorMap};
 use rustc::hir::map::Node;
-use rustc::lint::{LintContext, LateContext, Level, Lint};
+use rustc::lint::{LintContext, LateContext, Level, Lint, LintId, LintSource};
 use rustc::session::Session;
 use rustc::traits::Reveal;
 use rustc::traits;
@@ -13,7 +14,10 @@ use rustc::ty::layout::TargetDataLayout;
 use rustc::mir::transform::MirSource;
 use rustc_errors;
 use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::env;
+use std::fs;
 use std::mem;
 use std::str::FromStr;
 use syntax::ast::{self, LitKind};
@@ -31,6 +35,8 @@ pub mod paths;
 pub mod sugg;
 pub mod inspector;
 pub mod internal_lints;
+pub mod loop_body;
+pub mod summary;
 pub use self::hir::{SpanlessEq, SpanlessHash};
 
 pub type MethodArgs = HirVec<P<Expr>>;
@@ -182,6 +188,14 @@ pub fn match_type(cx: &LateContext, ty: ty::Ty, path: &[&str]) -> bool {
     }
 }
 
+/// Check if type is struct, enum or union type with any of the given def paths.
+pub fn match_any_type(cx: &LateContext, ty: ty::Ty, paths: &[&[&str]]) -> bool {
+    match ty.sty {
+        ty::TyAdt(adt, _) => paths.iter().any(|path| match_def_path(cx.tcx, adt.did, path)),
+        _ => false,
+    }
+}
+
 /// Check if the method call given in `expr` belongs to given type.
 pub fn match_impl_method(cx: &LateContext, expr: &Expr, path: &[&str]) -> bool {
     let method_call = ty::MethodCall::expr(expr.id);
@@ -340,6 +354,44 @@ pub fn implements_trait<'a, 'tcx>(
     })
 }
 
+/// Key of the `ImplementsTraitCache`: the arguments of `implements_trait`, with erased regions.
+type ImplementsTraitKey<'tcx> = (ty::Ty<'tcx>, DefId, Vec<ty::Ty<'tcx>>, Option<NodeId>);
+
+/// A cache of `implements_trait` results, for lints which ask the same questions about the same
+/// types over and over. Evaluating an obligation requires a fresh inference context every time.
+///
+/// The cache borrows from the type context, it is meant to live for a single call of a lint pass
+/// method, eg. while checking a function.
+#[derive(Default)]
+pub struct ImplementsTraitCache<'tcx> {
+    results: RefCell<HashMap<ImplementsTraitKey<'tcx>, bool>>,
+}
+
+impl<'tcx> ImplementsTraitCache<'tcx> {
+    /// Same as `implements_trait`, but only evaluates each query once.
+    pub fn implements_trait<'a>(
+        &self,
+        cx: &LateContext<'a, 'tcx>,
+        ty: ty::Ty<'tcx>,
+        trait_id: DefId,
+        ty_params: &[ty::Ty<'tcx>],
+        parent_node_id: Option<NodeId>
+    ) -> bool {
+        let key = (cx.tcx.erase_regions(&ty),
+                   trait_id,
+                   ty_params.iter().map(|param| cx.tcx.erase_regions(param)).collect::<Vec<_>>(),
+                   parent_node_id);
+
+        if let Some(&result) = self.results.borrow().get(&key) {
+            return result;
+        }
+
+        let result = implements_trait(cx, key.0, trait_id, &key.2, parent_node_id);
+        self.results.borrow_mut().insert(key, result);
+        result
+    }
+}
+
 /// Resolve the definition of a node from its `NodeId`.
 pub fn resolve_node(cx: &LateContext, qpath: &QPath, id: NodeId) -> def::Def {
     cx.tables.qpath_def(qpath, id)
@@ -413,6 +465,27 @@ pub fn snippet_block<'a, 'b, T: LintContext<'b>>(cx: &T, span: Span, default: &'
     trim_multiline(snip, true)
 }
 
+/// Indent all lines but the first of `s` with the indentation of the line `span` starts on.
+///
+/// Snippets obtained with `snippet_block` lose their original indentation, this can be used to
+/// make a multi-line suggestion line up with the code it replaces.
+pub fn reindent_multiline<'a, T: LintContext<'a>>(cx: &T, s: &str, span: Span) -> String {
+    let lo = cx.sess().codemap().lookup_char_pos(span.lo);
+    let indent = lo.file
+        .get_line(lo.line - 1 /* line numbers in `Loc` are 1-based */)
+        .map_or(String::new(), |line| line.chars().take_while(|&c| c == ' ' || c == '\t').collect());
+
+    s.lines()
+        .enumerate()
+        .map(|(i, line)| if i == 0 || line.is_empty() {
+            line.to_owned()
+        } else {
+            format!("{}{}", indent, line)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 /// Like `snippet_block`, but add braces if the expr is not an `ExprBlock`.
 /// Also takes an `Option<String>` which can be put inside the braces.
 pub fn expr_block<'a, 'b, T: LintContext<'b>>(
@@ -514,6 +587,16 @@ impl<'a> Drop for DiagnosticWrapper<'a> {
 }
 
 impl<'a> DiagnosticWrapper<'a> {
+    /// Explain that the lint is an error because of the `deny-lints` option, which sets the
+    /// default level of the lints it lists.
+    fn deny_lints_note<'b, T: LintContext<'b>>(&mut self, cx: &T, lint: &'static Lint) {
+        if let (Level::Deny, LintSource::Default) = cx.lints().get_level_source(LintId::of(lint)) {
+            if lint.default_level != Level::Deny {
+                self.0.note(&format!("`{}` is denied by the `deny-lints` option of `clippy.toml`", lint.name_lower()));
+            }
+        }
+    }
+
     fn wiki_link(&mut self, lint: &'static Lint) {
         if env::var("CLIPPY_DISABLE_WIKI_LINKS").is_err() {
             self.0.help(&format!("for further information visit https://github.com/Manishearth/rust-clippy/wiki#{}",
@@ -522,10 +605,80 @@ impl<'a> DiagnosticWrapper<'a> {
     }
 }
 
+/// The comment prefix recognized by `is_ignored_by_comment`.
+const IGNORE_COMMENT: &'static str = "clippy:ignore";
+
+/// Check whether the line above `span` contains a `// clippy:ignore <lint>` comment naming
+/// `lint`. Several lints can be listed, separated by spaces or commas.
+fn is_ignored_by_comment<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, span: Span) -> bool {
+    let lo = cx.sess().codemap().lookup_char_pos(span.lo);
+    if lo.line < 2 {
+        return false;
+    }
+    // line numbers in `Loc` are 1-based, we want the line before
+    if let Some(line) = lo.file.get_line(lo.line - 2) {
+        let line = line.trim();
+        if line.starts_with("//") {
+            let comment = line.trim_left_matches('/').trim();
+            if comment.starts_with(IGNORE_COMMENT) {
+                let name = lint.name_lower();
+                return comment[IGNORE_COMMENT.len()..]
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .any(|l| l == name);
+            }
+        }
+    }
+    false
+}
+
+/// Check whether `span` is in one of the files listed in the `CLIPPY_CHANGED_FILES` environment
+/// variable (separated like `PATH`), or whether the variable is not set. Relative paths are
+/// resolved from the current directory.
+fn is_in_changed_file<'a, T: LintContext<'a>>(cx: &T, span: Span) -> bool {
+    let changed = match env::var_os("CLIPPY_CHANGED_FILES") {
+        Some(changed) => changed,
+        None => return true,
+    };
+    let filemap = cx.sess().codemap().lookup_char_pos(span.lo).file;
+    // spans in files which don't exist on disk, e.g. `<std macros>`, are never in a changed file
+    let file = match fs::canonicalize(&filemap.name) {
+        Ok(file) => file,
+        Err(_) => return false,
+    };
+    env::split_paths(&changed)
+        .filter(|path| !path.as_os_str().is_empty())
+        .any(|path| fs::canonicalize(path).ok().map_or(false, |path| path == file))
+}
+
 pub fn span_lint<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, sp: Span, msg: &str) {
+    if is_ignored_by_comment(cx, lint, sp) || !is_in_changed_file(cx, sp) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
-    if cx.current_level(lint) != Level::Allow {
+    let level = cx.current_level(lint);
+    if level != Level::Allow {
+        db.deny_lints_note(cx, lint);
+        db.wiki_link(lint);
+        summary::record(lint, level);
+    }
+}
+
+/// Like `span_lint`, but highlights several sites of equal importance at once, the first one
+/// being reported as the location of the lint.
+pub fn span_lint_many<'a, T: LintContext<'a>>(cx: &T, lint: &'static Lint, spans: &[Span], msg: &str) {
+    let first = match spans.first() {
+        Some(&first) => first,
+        None => return,
+    };
+    if is_ignored_by_comment(cx, lint, first) || !is_in_changed_file(cx, first) {
+        return;
+    }
+    let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, MultiSpan::from_spans(spans.to_vec()), msg));
+    let level = cx.current_level(lint);
+    if level != Level::Allow {
+        db.deny_lints_note(cx, lint);
         db.wiki_link(lint);
+        summary::record(lint, level);
     }
 }
 
@@ -536,10 +689,16 @@ pub fn span_help_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     msg: &str,
     help: &str
 ) {
+    if is_ignored_by_comment(cx, lint, span) || !is_in_changed_file(cx, span) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
-    if cx.current_level(lint) != Level::Allow {
+    let level = cx.current_level(lint);
+    if level != Level::Allow {
         db.0.help(help);
+        db.deny_lints_note(cx, lint);
         db.wiki_link(lint);
+        summary::record(lint, level);
     }
 }
 
@@ -551,14 +710,20 @@ pub fn span_note_and_lint<'a, 'tcx: 'a, T: LintContext<'tcx>>(
     note_span: Span,
     note: &str
 ) {
+    if is_ignored_by_comment(cx, lint, span) || !is_in_changed_file(cx, span) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, span, msg));
-    if cx.current_level(lint) != Level::Allow {
+    let level = cx.current_level(lint);
+    if level != Level::Allow {
         if note_span == span {
             db.0.note(note);
         } else {
             db.0.span_note(note_span, note);
         }
+        db.deny_lints_note(cx, lint);
         db.wiki_link(lint);
+        summary::record(lint, level);
     }
 }
 
@@ -570,10 +735,30 @@ pub fn span_lint_and_then<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
     f: F
 ) where F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>)
 {
+    if is_ignored_by_comment(cx, lint, sp) || !is_in_changed_file(cx, sp) {
+        return;
+    }
     let mut db = DiagnosticWrapper(cx.struct_span_lint(lint, sp, msg));
-    if cx.current_level(lint) != Level::Allow {
+    let level = cx.current_level(lint);
+    if level != Level::Allow {
         f(&mut db.0);
+        db.deny_lints_note(cx, lint);
         db.wiki_link(lint);
+        summary::record(lint, level);
+    }
+}
+
+/// Like `span_lint_and_then`, but does nothing if `sp` comes from a macro expansion.
+pub fn span_lint_and_then_unless_macro<'a, 'tcx: 'a, T: LintContext<'tcx>, F>(
+    cx: &'a T,
+    lint: &'static Lint,
+    sp: Span,
+    msg: &str,
+    f: F
+) where F: for<'b> FnOnce(&mut DiagnosticBuilder<'b>)
+{
+    if !in_macro(sp) {
+        span_lint_and_then(cx, lint, sp, msg, f);
     }
 }
 
@@ -860,6 +1045,31 @@ pub fn is_refutable(cx: &LateContext, pat: &Pat) -> bool {
     }
 }
 
+/// Check whether the expression contains a `return`, not counting nested closures.
+pub fn contains_return(expr: &Expr) -> bool {
+    struct RetVisitor {
+        found: bool,
+    }
+
+    impl<'tcx> Visitor<'tcx> for RetVisitor {
+        fn visit_expr(&mut self, expr: &'tcx Expr) {
+            if let ExprRet(_) = expr.node {
+                self.found = true;
+            } else if !self.found {
+                intravisit::walk_expr(self, expr);
+            }
+        }
+
+        fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+            NestedVisitorMap::None
+        }
+    }
+
+    let mut visitor = RetVisitor { found: false };
+    visitor.visit_expr(expr);
+    visitor.found
+}
+
 /// Checks for the `#[automatically_derived]` attribute all `#[derive]`d implementations have.
 pub fn is_automatically_derived(attrs: &[ast::Attribute]) -> bool {
     attr::contains_name(attrs, "automatically_derived")
@@ -984,3 +1194,36 @@ pub fn type_size<'a, 'tcx>(cx: &LateContext<'a, 'tcx>, ty: ty::Ty<'tcx>) -> Opti
         .enter(|infcx| ty.layout(&infcx).ok().map(|lay| lay.size(&TargetDataLayout::parse(cx.sess())).bytes()))
 }
 
+/// Visitor checking whether the local with the given `DefId` is used.
+pub struct UsedVisitor<'a, 'tcx: 'a> {
+    pub cx: &'a LateContext<'a, 'tcx>,
+    pub id: DefId,
+    pub used: bool,
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for UsedVisitor<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx Expr) {
+        if_let_chain! {[
+            let ExprPath(ref qpath) = expr.node,
+            self.id == self.cx.tables.qpath_def(qpath, expr.id).def_id(),
+        ], {
+            self.used = true;
+            return;
+        }}
+        intravisit::walk_expr(self, expr);
+    }
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'tcx> {
+        NestedVisitorMap::All(&self.cx.tcx.hir)
+    }
+}
+
+/// Return whether the local with the given `DefId` is used in `expr`.
+pub fn used_in_expr<'a, 'tcx: 'a>(cx: &LateContext<'a, 'tcx>, id: DefId, expr: &'tcx Expr) -> bool {
+    let mut v = UsedVisitor {
+        cx: cx,
+        id: id,
+        used: false,
+    };
+    intravisit::walk_expr(&mut v, expr);
+    v.used
+}
@@ -42,6 +42,16 @@ impl<'a> Sugg<'a> {
     pub fn hir_opt(cx: &LateContext, expr: &hir::Expr) -> Option<Sugg<'a>> {
         snippet_opt(cx, expr.span).map(|snippet| {
             let snippet = Cow::Owned(snippet);
+
+            // ranges are desugared to structs or calls in HIR but still need parenthesis as method
+            // receivers
+            if let Some(range) = higher::range(expr) {
+                return match range.limits {
+                    ast::RangeLimits::HalfOpen => Sugg::BinOp(AssocOp::DotDot, snippet),
+                    ast::RangeLimits::Closed => Sugg::BinOp(AssocOp::DotDotDot, snippet),
+                };
+            }
+
             match expr.node {
                 hir::ExprAddrOf(..) |
                 hir::ExprBox(..) |
@@ -0,0 +1,91 @@
+//! Counts of the lints emitted while compiling a crate, used by the `cargo-clippy` driver to
+//! write a summary when the `CLIPPY_SUMMARY_JSON` env var is set.
+//!
+//! Only the lints emitted through the `span_lint*` helpers of `utils` are counted, lints emitted
+//! directly with `LintContext::span_lint`, including the compiler's own lints, are not.
+
+use rustc::lint::{Level, Lint};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// The number of times a lint was emitted, by severity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LintCounts {
+    pub warn: usize,
+    pub deny: usize,
+}
+
+thread_local! {
+    // the lints of a crate are all run on the compiler's thread
+    static COUNTS: RefCell<BTreeMap<String, LintCounts>> = RefCell::new(BTreeMap::new());
+}
+
+/// Record an emission of `lint` at `level`. `Allow`ed lints are not emitted and not recorded.
+pub fn record(lint: &'static Lint, level: Level) {
+    if level == Level::Allow {
+        return;
+    }
+    COUNTS.with(|counts| {
+        let mut counts = counts.borrow_mut();
+        let entry = counts.entry(lint.name_lower()).or_insert_with(LintCounts::default);
+        if level == Level::Warn {
+            entry.warn += 1;
+        } else {
+            entry.deny += 1;
+        }
+    });
+}
+
+/// Return the counts recorded so far on this thread, and reset them.
+pub fn take() -> BTreeMap<String, LintCounts> {
+    COUNTS.with(|counts| ::std::mem::replace(&mut *counts.borrow_mut(), BTreeMap::new()))
+}
+
+/// Format the counts as a JSON object of the form `{ "lint_name": { "warn": n, "deny": n } }`.
+pub fn to_json(counts: &BTreeMap<String, LintCounts>) -> String {
+    // lint names only consist of lowercase ASCII letters, digits and underscores, nothing to escape
+    let entries = counts.iter()
+        .map(|(name, counts)| format!("  \"{}\": {{ \"warn\": {}, \"deny\": {} }}", name, counts.warn, counts.deny))
+        .collect::<Vec<_>>();
+    if entries.is_empty() {
+        "{}\n".to_owned()
+    } else {
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+}
+
+/// Parse counts formatted by `to_json`. Return `None` if `json` is not in that format.
+pub fn from_json(json: &str) -> Option<BTreeMap<String, LintCounts>> {
+    let mut counts = BTreeMap::new();
+    for line in json.lines().map(str::trim) {
+        if line == "{" || line == "}" || line == "{}" {
+            continue;
+        }
+        // `"name": { "warn": n, "deny": n }`
+        let parts = line.split('"').collect::<Vec<_>>();
+        if parts.len() != 7 || parts[3] != "warn" || parts[5] != "deny" {
+            return None;
+        }
+        let number = |part: &str| part.trim_matches(|c: char| !c.is_digit(10)).parse::<usize>();
+        match (number(parts[4]), number(parts[6])) {
+            (Ok(warn), Ok(deny)) => {
+                counts.insert(parts[1].to_owned(),
+                              LintCounts {
+                                  warn: warn,
+                                  deny: deny,
+                              });
+            },
+            _ => return None,
+        }
+    }
+    Some(counts)
+}
+
+/// Add the counts of `other` to `counts`.
+pub fn merge(counts: &mut BTreeMap<String, LintCounts>, other: BTreeMap<String, LintCounts>) {
+    for (name, other) in other {
+        let entry = counts.entry(name).or_insert_with(LintCounts::default);
+        entry.warn += other.warn;
+        entry.deny += other.deny;
+    }
+}
@@ -1,9 +1,12 @@
 use rustc::hir::*;
+use rustc::hir::def_id::DefId;
 use rustc::lint::*;
+use rustc::middle::const_val::ConstVal;
 use rustc::ty;
 use rustc_const_eval::ConstContext;
 use syntax::codemap::Span;
-use utils::{higher, is_copy, snippet, span_lint_and_then};
+use utils::{higher, is_copy, snippet, span_help_and_lint, span_lint_and_sugg, span_lint_and_then, match_path,
+            match_type, in_macro, paths, used_in_expr, walk_ptrs_ty};
 
 /// **What it does:** Checks for usage of `&vec![..]` when using `&[..]` would
 /// be possible.
@@ -22,12 +25,81 @@ declare_lint! {
     "useless `vec!`"
 }
 
+/// **What it does:** Checks for `let mut v = Vec::new();` immediately followed
+/// by calls to `v.push(_)`.
+///
+/// **Why is this bad?** The `vec!` macro is more concise and allocates the
+/// vector with the right capacity right away.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// let mut v = Vec::new();
+/// v.push(1);
+/// v.push(2);
+/// ```
+/// could be written as
+/// ```rust
+/// let mut v = vec![1, 2];
+/// ```
+declare_lint! {
+    pub VEC_INIT_THEN_PUSH,
+    Warn,
+    "`Vec::new()` immediately followed by calls to `push`, which could be written with `vec![]`"
+}
+
+/// **What it does:** Checks for `.len()` or `.is_empty()` called directly on
+/// a `vec!` macro.
+///
+/// **Why is this bad?** The result is known at compile time, allocating a
+/// vector just to compute it is wasteful.
+///
+/// **Known problems:** The elements of the `vec!` are still evaluated, which
+/// matters if they have side effects.
+///
+/// **Example:**
+/// ```rust
+/// let n = vec![1, 2, 3].len();
+/// ```
+/// could be written as
+/// ```rust
+/// let n = 3;
+/// ```
+declare_lint! {
+    pub VEC_MACRO_LEN,
+    Warn,
+    "`.len()` or `.is_empty()` on a `vec!`, whose value is known at compile time"
+}
+
+/// **What it does:** Checks for `_.extend(vec![..])` where the elements are
+/// `Copy`.
+///
+/// **Why is this bad?** The `vec!` allocates a vector on the heap only to move
+/// its elements, they can be copied from an array instead.
+///
+/// **Known problems:** None.
+///
+/// **Example:**
+/// ```rust
+/// v.extend(vec![1, 2, 3]);
+/// ```
+/// could be written as
+/// ```rust
+/// v.extend_from_slice(&[1, 2, 3]);
+/// ```
+declare_lint! {
+    pub EXTEND_WITH_VEC_MACRO,
+    Warn,
+    "`.extend(vec![..])`, which needlessly allocates a vector"
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct Pass;
 
 impl LintPass for Pass {
     fn get_lints(&self) -> LintArray {
-        lint_array!(USELESS_VEC)
+        lint_array!(USELESS_VEC, VEC_INIT_THEN_PUSH, VEC_MACRO_LEN, EXTEND_WITH_VEC_MACRO)
     }
 }
 
@@ -37,10 +109,10 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         if_let_chain!{[
             let ty::TypeVariants::TyRef(_, ref ty) = cx.tables.expr_ty_adjusted(expr).sty,
             let ty::TypeVariants::TySlice(..) = ty.ty.sty,
-            let ExprAddrOf(_, ref addressee) = expr.node,
+            let ExprAddrOf(mutbl, ref addressee) = expr.node,
             let Some(vec_args) = higher::vec_macro(cx, addressee),
         ], {
-            check_vec_macro(cx, &vec_args, expr.span);
+            check_vec_macro(cx, &vec_args, expr.span, Some(mutbl));
         }}
 
         // search for `for _ in vec![…]`
@@ -51,18 +123,173 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for Pass {
         ], {
             // report the error around the `vec!` not inside `<std macros>:`
             let span = arg.span.ctxt.outer().expn_info().map(|info| info.call_site).expect("unable to get call_site");
-            check_vec_macro(cx, &vec_args, span);
+            check_vec_macro(cx, &vec_args, span, Some(MutImmutable));
+        }}
+
+        // search for `vec![…].iter()….collect()`, the array can be iterated just the same
+        // (`.into_iter()` is not linted, on an array it would yield references instead)
+        if_let_chain!{[
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node == "collect",
+            let Some(iter_recv) = iter_receiver(&args[0]),
+            let Some(vec_args) = higher::vec_macro(cx, iter_recv),
+            // `[elem; n]` requires `elem` to be `Copy`
+            !matches!(vec_args, higher::VecArgs::Repeat(..)) ||
+            is_copy(cx, vec_type(cx.tables.expr_ty(iter_recv)), cx.tcx.hir.get_parent(expr.id)),
+        ], {
+            let span = iter_recv.span.ctxt.outer().expn_info().map(|info| info.call_site)
+                                .expect("unable to get call_site");
+            check_vec_macro(cx, &vec_args, span, None);
+        }}
+
+        // search for `vec![…].len()` and `vec![…].is_empty()`, their value is known statically
+        if_let_chain!{[
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node == "len" || name.node == "is_empty",
+            args.len() == 1,
+            let Some(vec_args) = higher::vec_macro(cx, &args[0]),
+            let Some(len) = vec_macro_len(cx, &vec_args),
+        ], {
+            let value = if name.node == "len" {
+                len.to_string()
+            } else {
+                (len == 0).to_string()
+            };
+            span_help_and_lint(cx,
+                               VEC_MACRO_LEN,
+                               expr.span,
+                               &format!("`.{}()` on a `vec!` is always `{}`", name.node, value),
+                               &format!("consider using `{}` directly", value));
+        }}
+
+        // search for `_.extend(vec![…])` with `Copy` elements, which can be copied from an array
+        if_let_chain!{[
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node == "extend",
+            args.len() == 2,
+            let Some(vec_args) = higher::vec_macro(cx, &args[1]),
+            is_copy(cx, vec_type(cx.tables.expr_ty(&args[1])), cx.tcx.hir.get_parent(expr.id)),
+            let Some(array) = vec_macro_as_array(cx, &vec_args),
+        ], {
+            check_extend(cx, expr, &args[0], &array);
+        }}
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'a, 'tcx>, block: &'tcx Block) {
+        for (i, stmt) in block.stmts.iter().enumerate() {
+            if_let_chain!{[
+                !in_macro(stmt.span),
+                let StmtDecl(ref decl, _) = stmt.node,
+                let DeclLocal(ref local) = decl.node,
+                let PatKind::Binding(BindByValue(MutMutable), def_id, _, None) = local.pat.node,
+                let Some(ref init) = local.init,
+                let ExprCall(ref fun, ref args) = init.node,
+                args.is_empty(),
+                let ExprPath(ref qpath) = fun.node,
+                match_path(qpath, &["Vec", "new"]),
+            ], {
+                check_init_then_push(cx, stmt.span, init, def_id, &block.stmts[i + 1..]);
+            }}
+        }
+    }
+}
+
+/// Lint the run of `push` statements following the declaration `decl_span` of the vector `vec_id`
+/// initialized with `init`.
+fn check_init_then_push<'a, 'tcx: 'a>(
+    cx: &LateContext<'a, 'tcx>,
+    decl_span: Span,
+    init: &Expr,
+    vec_id: DefId,
+    following: &'tcx [Stmt]
+) {
+    let mut pushes = vec![];
+    for stmt in following {
+        if_let_chain!{[
+            !in_macro(stmt.span),
+            let StmtSemi(ref expr, _) = stmt.node,
+            let ExprMethodCall(ref name, _, ref args) = expr.node,
+            name.node == "push",
+            args.len() == 2,
+            let ExprPath(ref qpath) = args[0].node,
+            cx.tables.qpath_def(qpath, args[0].id).def_id() == vec_id,
+            !used_in_expr(cx, vec_id, &args[1]),
+        ], {
+            pushes.push((stmt.span, &args[1]));
+            continue;
         }}
+        break;
     }
+
+    if pushes.is_empty() {
+        return;
+    }
+
+    let span = Span {
+        lo: decl_span.lo,
+        hi: pushes[pushes.len() - 1].0.hi,
+        ctxt: decl_span.ctxt,
+    };
+    span_lint_and_then(cx,
+                       VEC_INIT_THEN_PUSH,
+                       span,
+                       "calls to `push` immediately after creation",
+                       |db| {
+        let binding = Span { hi: init.span.lo, ..decl_span };
+        let values = pushes.iter().map(|&(_, value)| snippet(cx, value.span, "..")).collect::<Vec<_>>();
+        db.span_suggestion(span,
+                           "consider using the `vec![]` macro",
+                           format!("{}vec![{}];", snippet(cx, binding, ".."), values.join(", ")));
+    });
+}
+
+/// Walk down the chain of method calls ending with `expr` and return the receiver of its `.iter()`
+/// call, if any.
+fn iter_receiver(mut expr: &Expr) -> Option<&Expr> {
+    while let ExprMethodCall(ref name, _, ref args) = expr.node {
+        if name.node == "iter" && args.len() == 1 {
+            return Some(&args[0]);
+        }
+        expr = &args[0];
+    }
+    None
+}
+
+/// Lint the `vec!` macro call at `span`, suggesting a slice borrowed with the `slice` mutability
+/// or an array if `slice` is `None`.
+fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span, slice: Option<Mutability>) {
+    let prefix = match slice {
+        Some(MutImmutable) => "&",
+        Some(MutMutable) => "&mut ",
+        None => "",
+    };
+    let snippet = match vec_macro_as_array(cx, vec_args) {
+        Some(array) => format!("{}{}", prefix, array),
+        None => return,
+    };
+
+    span_lint_and_then(cx,
+                       USELESS_VEC,
+                       span,
+                       "useless use of `vec!`",
+                       |db| {
+        let help = if slice.is_some() {
+            "you can use a slice directly"
+        } else {
+            "you can use an array directly"
+        };
+        db.span_suggestion(span, help, snippet);
+    });
 }
 
-fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span) {
-    let snippet = match *vec_args {
+/// Return the array equivalent to the `vec!` macro call, if its length is known at compile time.
+fn vec_macro_as_array(cx: &LateContext, vec_args: &higher::VecArgs) -> Option<String> {
+    match *vec_args {
         higher::VecArgs::Repeat(elem, len) => {
             if ConstContext::with_tables(cx.tcx, cx.tables).eval(len).is_ok() {
-                format!("&[{}; {}]", snippet(cx, elem.span, "elem"), snippet(cx, len.span, "len")).into()
+                Some(format!("[{}; {}]", snippet(cx, elem.span, "elem"), snippet(cx, len.span, "len")))
             } else {
-                return;
+                None
             }
         },
         higher::VecArgs::Vec(args) => {
@@ -73,18 +300,37 @@ fn check_vec_macro(cx: &LateContext, vec_args: &higher::VecArgs, span: Span) {
                     ctxt: args[0].span.ctxt,
                 };
 
-                format!("&[{}]", snippet(cx, span, "..")).into()
+                Some(format!("[{}]", snippet(cx, span, "..")))
             } else {
-                "&[]".into()
+                Some("[]".to_owned())
             }
         },
+    }
+}
+
+/// Lint the call `expr` of `dst.extend(vec![…])`, `array` being the equivalent of the `vec!`.
+fn check_extend(cx: &LateContext, expr: &Expr, dst: &Expr, array: &str) {
+    let dst_snippet = snippet(cx, dst.span, "..");
+    let (help, sugg) = if match_type(cx, walk_ptrs_ty(cx.tables.expr_ty(dst)), &paths::VEC) {
+        ("consider using `extend_from_slice`", format!("{}.extend_from_slice(&{})", dst_snippet, array))
+    } else {
+        ("consider extending from an array", format!("{}.extend({}.iter().cloned())", dst_snippet, array))
     };
 
-    span_lint_and_then(cx,
-                       USELESS_VEC,
-                       span,
-                       "useless use of `vec!`",
-                       |db| { db.span_suggestion(span, "you can use a slice directly", snippet); });
+    span_lint_and_sugg(cx, EXTEND_WITH_VEC_MACRO, expr.span, "use of `vec!` to extend a collection", help, sugg);
+}
+
+/// Return the number of elements of the `vec!` macro call, if it is known at compile time.
+fn vec_macro_len(cx: &LateContext, vec_args: &higher::VecArgs) -> Option<u128> {
+    match *vec_args {
+        higher::VecArgs::Repeat(_, len) => {
+            match ConstContext::with_tables(cx.tcx, cx.tables).eval(len) {
+                Ok(ConstVal::Integral(len)) => len.to_u128(),
+                _ => None,
+            }
+        },
+        higher::VecArgs::Vec(args) => Some(args.len() as u128),
+    }
 }
 
 /// Return the item type of the vector (ie. the `T` in `Vec<T>`).
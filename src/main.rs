@@ -1,4 +1,3 @@
-// error-pattern:yummy
 #![feature(box_syntax)]
 #![feature(rustc_private)]
 
@@ -12,13 +11,16 @@ extern crate rustc_errors;
 extern crate rustc_plugin;
 extern crate syntax;
 
+use clippy_lints::utils::summary;
 use rustc_driver::{driver, CompilerCalls, RustcDefaultCalls, Compilation};
 use rustc::session::{config, Session};
 use rustc::session::config::{Input, ErrorOutputType};
+use std::fmt;
+use std::fs::{self, File};
 use std::path::PathBuf;
 use std::process::{self, Command};
 use syntax::ast;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 
 extern crate cargo_metadata;
 
@@ -130,6 +132,7 @@ Usage:
 Common options:
     -h, --help               Print this message
     --features               Features to compile for the package
+    --changed-files=<paths>  Only report lints in these files, separated like `PATH`
     -V, --version            Print version info and exit
 
 Other options are the same as `cargo rustc`.
@@ -161,10 +164,6 @@ fn show_version() {
 pub fn main() {
     use std::env;
 
-    if env::var("CLIPPY_DOGFOOD").map(|_| true).unwrap_or(false) {
-        panic!("yummy");
-    }
-
     // Check for version and help flags even when invoked as 'cargo-clippy'
     if std::env::args().any(|a| a == "--help" || a == "-h") {
         show_help();
@@ -175,6 +174,13 @@ pub fn main() {
         return;
     }
 
+    // only an exact `1` enables dogfood mode, so tools setting the variable to `0` or an empty
+    // string don't trigger it
+    if env::var("CLIPPY_DOGFOOD").map_or(false, |v| v == "1") {
+        dogfood();
+        return;
+    }
+
     if let Some("clippy") = std::env::args().nth(1).as_ref().map(AsRef::as_ref) {
         // this arm is executed on the initial call to `cargo clippy`
 
@@ -190,6 +196,19 @@ pub fn main() {
                 process::exit(101);
             };
 
+        // the lint helpers of the compiler spawned by cargo read the list of files to lint from the
+        // environment
+        if let Some(changed_files) = std::env::args()
+            .skip(2)
+            .find(|val| val.starts_with("--changed-files=")) {
+            env::set_var("CLIPPY_CHANGED_FILES", &changed_files["--changed-files=".len()..]);
+        }
+
+        // the summary of each target is added to the file, start from scratch
+        if let Ok(path) = env::var("CLIPPY_SUMMARY_JSON") {
+            let _ = fs::remove_file(path);
+        }
+
         let manifest_path = manifest_path_arg.map(|arg| PathBuf::from(Path::new(&arg["--manifest-path=".len()..])));
 
         let current_dir = std::env::current_dir();
@@ -214,7 +233,9 @@ pub fn main() {
             .expect("could not find matching package");
         let package = metadata.packages.remove(package_index);
         for target in package.targets {
-            let args = std::env::args().skip(2);
+            let args = std::env::args()
+                .skip(2)
+                .filter(|val| !val.starts_with("--changed-files="));
             if let Some(first) = target.kind.get(0) {
                 if target.kind.len() > 1 || first.ends_with("lib") {
                     if let Err(code) = process(std::iter::once("--lib".to_owned()).chain(args)) {
@@ -235,36 +256,26 @@ pub fn main() {
         // this arm is executed when cargo-clippy runs `cargo rustc` with the `RUSTC`
         // env var set to itself
 
-        let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
-        let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
-        let sys_root = if let (Some(home), Some(toolchain)) = (home, toolchain) {
-            format!("{}/toolchains/{}", home, toolchain)
+        // this conditional check for the --sysroot flag is there so users can call
+        // `cargo-clippy` directly
+        // without having to pass --sysroot or anything
+        let sys_root = if env::args().any(|s| s == "--sysroot") {
+            None
         } else {
-            option_env!("SYSROOT")
-                .map(|s| s.to_owned())
-                .or_else(|| {
-                    Command::new("rustc")
-                        .arg("--print")
-                        .arg("sysroot")
-                        .output()
-                        .ok()
-                        .and_then(|out| String::from_utf8(out.stdout).ok())
-                        .map(|s| s.trim().to_owned())
-                })
-                .expect("need to specify SYSROOT env var during clippy compilation, or use rustup or multirust")
+            Some(sysroot().unwrap_or_else(|err| {
+                let _ = writeln!(io::stderr(), "error: {}\n\n{}", err, SYSROOT_HELP);
+                process::exit(101);
+            }))
         };
 
         rustc_driver::in_rustc_thread(|| {
-            // this conditional check for the --sysroot flag is there so users can call
-            // `cargo-clippy` directly
-            // without having to pass --sysroot or anything
-            let mut args: Vec<String> = if env::args().any(|s| s == "--sysroot") {
-                env::args().collect()
-            } else {
+            let mut args: Vec<String> = if let Some(sys_root) = sys_root {
                 env::args()
                     .chain(Some("--sysroot".to_owned()))
                     .chain(Some(sys_root))
                     .collect()
+            } else {
+                env::args().collect()
             };
 
             // this check ensures that dependencies are built but not linted and the final
@@ -278,6 +289,11 @@ pub fn main() {
 
             let mut ccc = ClippyCompilerCalls::new(clippy_enabled);
             let (result, _) = rustc_driver::run_compiler(&args, &mut ccc, None, None);
+            if clippy_enabled {
+                if let Ok(path) = env::var("CLIPPY_SUMMARY_JSON") {
+                    write_summary(&path);
+                }
+            }
             if let Err(err_count) = result {
                 if err_count > 0 {
                     std::process::exit(1);
@@ -288,6 +304,103 @@ pub fn main() {
     }
 }
 
+/// Write the number of emitted lints by name and severity as JSON to `path`, adding the counts
+/// already in that file, as `cargo clippy` runs the driver once per target of the package.
+fn write_summary(path: &str) {
+    let mut counts = summary::take();
+    let mut previous = String::new();
+    if File::open(path).and_then(|mut file| file.read_to_string(&mut previous)).is_ok() {
+        if let Some(previous) = summary::from_json(&previous) {
+            summary::merge(&mut counts, previous);
+        }
+    }
+    let json = summary::to_json(&counts);
+    if let Err(err) = File::create(path).and_then(|mut file| file.write_all(json.as_bytes())) {
+        let _ = writeln!(io::stderr(), "error: could not write the lint summary to `{}`: {}", path, err);
+    }
+}
+
+/// Run clippy on clippy's own sources, exiting with a non-zero code if it finds anything. The
+/// sources are those of the `--manifest-path=` argument, or of the current directory.
+fn dogfood() {
+    let manifest_path = std::env::args()
+        .skip(1)
+        .find(|arg| arg.starts_with("--manifest-path="))
+        .map(|arg| PathBuf::from(&arg["--manifest-path=".len()..]))
+        .unwrap_or_else(|| PathBuf::from("Cargo.toml"));
+    let crate_dir = manifest_path.parent().map_or_else(|| PathBuf::from("."), Path::to_path_buf);
+
+    let path = std::env::current_exe().expect("current executable path invalid");
+    let status = Command::new(path)
+        .arg("clippy")
+        .arg(format!("--manifest-path={}", manifest_path.display()))
+        .args(&["--", "-D", "clippy", "-D", "clippy_pedantic", "-D", "clippy_internal"])
+        // the nested invocations must lint, not recurse into dogfood mode
+        .env_remove("CLIPPY_DOGFOOD")
+        // don't contend for the lock of, or invalidate, the build directory of the caller
+        .env("CARGO_TARGET_DIR", crate_dir.join("target").join("dogfood"))
+        .status()
+        .expect("could not run cargo-clippy");
+
+    if status.success() {
+        let _ = writeln!(io::stderr(), "dogfood: clippy found no issues in its own sources");
+    } else {
+        let _ = writeln!(io::stderr(), "dogfood: clippy found issues in its own sources");
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+const SYSROOT_HELP: &str = "clippy needs to know the sysroot of the Rust toolchain it runs with. Either:
+
+    * install Rust through rustup (https://rustup.rs) and build clippy with it,
+    * set the `SYSROOT` env var when building clippy, e.g. to the output of `rustc --print sysroot`,
+    * make sure `rustc` is in your `PATH`, or
+    * pass `--sysroot <path>` explicitly.";
+
+/// Reasons why the sysroot could not be determined.
+enum SysrootError {
+    /// `rustc` could not be found in `PATH`.
+    RustcNotFound,
+    /// `rustc --print sysroot` could not be run or did not succeed.
+    RustcFailed(String),
+}
+
+impl fmt::Display for SysrootError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SysrootError::RustcNotFound => write!(f, "could not find the sysroot: `rustc` is not in your `PATH`"),
+            SysrootError::RustcFailed(ref msg) => {
+                write!(f, "could not find the sysroot: `rustc --print sysroot` failed: {}", msg)
+            },
+        }
+    }
+}
+
+/// Find the sysroot from the rustup or multirust env vars set when clippy was compiled, the
+/// `SYSROOT` env var or as a last resort by asking `rustc`.
+fn sysroot() -> Result<String, SysrootError> {
+    let home = option_env!("RUSTUP_HOME").or(option_env!("MULTIRUST_HOME"));
+    let toolchain = option_env!("RUSTUP_TOOLCHAIN").or(option_env!("MULTIRUST_TOOLCHAIN"));
+    if let (Some(home), Some(toolchain)) = (home, toolchain) {
+        return Ok(format!("{}/toolchains/{}", home, toolchain));
+    }
+    if let Some(sys_root) = option_env!("SYSROOT") {
+        return Ok(sys_root.to_owned());
+    }
+
+    let out = match Command::new("rustc").arg("--print").arg("sysroot").output() {
+        Ok(out) => out,
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Err(SysrootError::RustcNotFound),
+        Err(err) => return Err(SysrootError::RustcFailed(err.to_string())),
+    };
+    if !out.status.success() {
+        return Err(SysrootError::RustcFailed(String::from_utf8_lossy(&out.stderr).trim().to_owned()));
+    }
+    String::from_utf8(out.stdout)
+        .map(|s| s.trim().to_owned())
+        .map_err(|_| SysrootError::RustcFailed("the output is not valid UTF-8".to_owned()))
+}
+
 fn process<I>(old_args: I) -> Result<(), i32>
     where I: Iterator<Item = String>
 {
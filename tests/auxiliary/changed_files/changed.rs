@@ -0,0 +1,3 @@
+pub fn changed(x: i32) -> i32 {
+    --x
+}
@@ -0,0 +1,7 @@
+#![allow(dead_code)]
+
+mod changed;
+
+fn unchanged(x: i32) -> i32 {
+    --x
+}
@@ -0,0 +1,11 @@
+#![deny(eq_op)]
+#![allow(dead_code)]
+
+fn double_neg(x: i32) -> i32 {
+    let _ = --x;
+    --x
+}
+
+fn eq_op(x: i32) -> bool {
+    x == x
+}
@@ -0,0 +1,15 @@
+use std::process::Command;
+
+#[test]
+fn lints_in_unlisted_files_are_not_reported() {
+    let output = Command::new("target/debug/cargo-clippy")
+        .args(&["tests/auxiliary/changed_files/lib.rs", "--crate-type=lib", "-Zno-trans"])
+        .env("CLIPPY_CHANGED_FILES", "tests/auxiliary/changed_files/changed.rs")
+        .output()
+        .expect("could not run cargo-clippy");
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("changed_files/changed.rs:2:5"), "{}", stderr);
+    assert!(!stderr.contains("changed_files/lib.rs"), "{}", stderr);
+}
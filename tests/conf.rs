@@ -0,0 +1,40 @@
+extern crate clippy_lints;
+
+use clippy_lints::utils::conf;
+use std::path::Path;
+
+#[test]
+fn test_default() {
+    let (conf, errors) = conf::read(None);
+    assert!(errors.is_empty());
+    assert_eq!(conf.blacklisted_names, ["foo", "bar", "baz", "quux"]);
+    assert_eq!(conf.cyclomatic_complexity_threshold, 25);
+    assert_eq!(conf.unit_arm_style, "()");
+}
+
+#[test]
+fn test_sample() {
+    let (conf, errors) = conf::read(Some(Path::new("tests/auxiliary/conf_sample.toml")));
+    assert!(errors.is_empty());
+    assert_eq!(conf.blacklisted_names, ["toto", "tata"]);
+    assert_eq!(conf.cyclomatic_complexity_threshold, 10);
+    assert_eq!(conf.too_many_lines_threshold, 50);
+    assert_eq!(conf.unit_arm_style, "{}");
+    // keys missing from the file keep their default value
+    assert_eq!(conf.too_many_arguments_threshold, 7);
+}
+
+#[test]
+fn test_unknown_key() {
+    let (_, errors) = conf::read(Some(Path::new("tests/auxiliary/conf_unknown_key.toml")));
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn test_bad_unit_arm_style() {
+    let (conf, errors) = conf::read(Some(Path::new("tests/auxiliary/conf_bad_unit_arm_style.toml")));
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].to_string(), "`unit-arm-style` is expected to be one of `()`, `{}` but is `{ }`");
+    // the invalid value is replaced by the default one
+    assert_eq!(conf.unit_arm_style, "()");
+}
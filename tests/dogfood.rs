@@ -20,7 +20,6 @@ fn dogfood() {
 
     let mut config = compiletest::default_config();
 
-    let cfg_mode = "run-fail".parse().expect("Invalid mode");
     let mut s = String::new();
     s.push_str(" -L target/debug/");
     s.push_str(" -L target/debug/deps");
@@ -30,19 +29,22 @@ fn dogfood() {
         config.filter = Some(name.to_owned())
     }
 
-    config.mode = cfg_mode;
     config.verbose = true;
 
-    let files = ["src/main.rs", "src/lib.rs", "clippy_lints/src/lib.rs"];
+    // the libraries can't be run and fail with their `error-pattern`, the driver lints the whole crate in
+    // dogfood mode and only succeeds if it found nothing
+    let files = [("src/main.rs", "run-pass"), ("src/lib.rs", "run-fail"), ("clippy_lints/src/lib.rs", "run-fail")];
+
+    for &(file, mode) in &files {
+        config.mode = mode.parse().expect("Invalid mode");
 
-    for file in &files {
         let paths = TestPaths {
             base: PathBuf::new(),
             file: PathBuf::from(file),
             relative_dir: PathBuf::new(),
         };
 
-        set_var("CLIPPY_DOGFOOD", "tastes like chicken");
+        set_var("CLIPPY_DOGFOOD", "1");
 
         compiletest::runtest::run(config.clone(), &paths);
     }
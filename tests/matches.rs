@@ -30,3 +30,23 @@ fn test_overlapping() {
                              sp(5, Bound::Included(6)),
                              sp(6, Bound::Included(11))]));
 }
+
+#[test]
+fn test_intersection() {
+    use clippy_lints::matches::intersection;
+    use syntax::codemap::DUMMY_SP;
+
+    let sp = |s, e| {
+        clippy_lints::matches::SpannedRange {
+            span: DUMMY_SP,
+            node: (s, e),
+        }
+    };
+
+    assert_eq!((3, Bound::Included(4)), intersection(&sp(1, Bound::Included(4)), &sp(3, Bound::Included(6))));
+    assert_eq!((3, Bound::Included(4)), intersection(&sp(3, Bound::Included(6)), &sp(1, Bound::Included(4))));
+    assert_eq!((2, Bound::Included(2)), intersection(&sp(0, Bound::Included(5)), &sp(2, Bound::Included(2))));
+    assert_eq!((0, Bound::Excluded(11)), intersection(&sp(0, Bound::Excluded(11)), &sp(0, Bound::Included(11))));
+    assert_eq!((0, Bound::Included(10)), intersection(&sp(0, Bound::Excluded(11)), &sp(0, Bound::Included(10))));
+    assert_eq!((5, Bound::Excluded(6)), intersection(&sp(5, Bound::Unbounded), &sp(1, Bound::Excluded(6))));
+}
@@ -0,0 +1,25 @@
+#![feature(rustc_private)]
+
+extern crate clippy_lints;
+
+use clippy_lints::utils::paths;
+
+fn is_prefix(prefix: &[&str], path: &[&str]) -> bool {
+    path.len() == prefix.len() + 1 && path.starts_with(prefix)
+}
+
+#[test]
+fn test_type_paths_match_item_paths() {
+    assert!(is_prefix(&paths::BOX, &paths::BOX_NEW));
+    assert!(is_prefix(&paths::OPTION, &paths::OPTION_SOME));
+    assert!(is_prefix(&paths::OPTION, &paths::OPTION_NONE));
+    assert!(is_prefix(&paths::RESULT, &paths::RESULT_OK));
+    assert!(is_prefix(&paths::RESULT, &paths::RESULT_ERR));
+    assert!(is_prefix(&paths::REGEX, &paths::REGEX_NEW));
+    assert!(is_prefix(&paths::REGEX_BYTES, &paths::REGEX_BYTES_NEW));
+}
+
+#[test]
+fn test_module_paths() {
+    assert!(is_prefix(&paths::OPS_MODULE, &paths::RANGE));
+}
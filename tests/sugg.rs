@@ -0,0 +1,45 @@
+#![feature(rustc_private)]
+
+extern crate clippy_lints;
+extern crate syntax;
+
+use clippy_lints::utils::sugg::{self, Sugg};
+use syntax::ast::{BinOpKind, RangeLimits};
+
+fn non_paren(s: &'static str) -> Sugg<'static> {
+    Sugg::NonParen(s.into())
+}
+
+#[test]
+fn test_range() {
+    let range = non_paren("0").range(non_paren("10"), RangeLimits::HalfOpen);
+    assert_eq!("0..10", range.to_string());
+
+    let range = non_paren("0").range(non_paren("10"), RangeLimits::Closed);
+    assert_eq!("0...10", range.to_string());
+
+    // arithmetic binds tighter than ranges
+    let range = (non_paren("a") + non_paren("1")).range(non_paren("b") - sugg::ONE, RangeLimits::HalfOpen);
+    assert_eq!("a + 1..b - 1", range.to_string());
+
+    // and so do comparisons
+    let lhs = sugg::make_binop(BinOpKind::Lt, &non_paren("a"), &non_paren("b"));
+    let range = lhs.range(non_paren("c"), RangeLimits::HalfOpen);
+    assert_eq!("a < b..c", range.to_string());
+
+    // ranges are not associative
+    let lhs = non_paren("a").range(non_paren("b"), RangeLimits::HalfOpen);
+    let range = lhs.range(non_paren("c"), RangeLimits::HalfOpen);
+    assert_eq!("(a..b)..c", range.to_string());
+}
+
+#[test]
+fn test_range_method_call() {
+    let range = non_paren("0").range(non_paren("10"), RangeLimits::HalfOpen);
+    assert_eq!("(0..10).rev()", format!("{}.rev()", range.maybe_par()));
+
+    let range = non_paren("0").range(non_paren("10"), RangeLimits::Closed);
+    assert_eq!("(0...10).rev()", format!("{}.rev()", range.maybe_par()));
+
+    assert_eq!("x.rev()", format!("{}.rev()", non_paren("x").maybe_par()));
+}
@@ -0,0 +1,56 @@
+#![feature(rustc_private)]
+
+extern crate clippy_lints;
+
+use clippy_lints::utils::summary;
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use std::process::Command;
+
+fn run_on_fixture(path: &Path) -> String {
+    let output = Command::new("target/debug/cargo-clippy")
+        .args(&["tests/auxiliary/summary_fixture.rs", "--crate-type=lib", "-Zno-trans"])
+        .env("CLIPPY_SUMMARY_JSON", path)
+        .output()
+        .expect("could not run cargo-clippy");
+
+    // the fixture denies a lint, the summary must be written even though compilation fails
+    assert_eq!(output.status.code(), Some(1));
+
+    let mut json = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut json))
+        .expect("could not read the summary");
+    json
+}
+
+#[test]
+fn summary_is_written_to_the_given_path() {
+    let path = std::env::temp_dir().join("clippy_summary_json_test.json");
+    let _ = fs::remove_file(&path);
+
+    assert_eq!(run_on_fixture(&path),
+               "{\n  \"double_neg\": { \"warn\": 2, \"deny\": 0 },\n  \"eq_op\": { \"warn\": 0, \"deny\": 1 }\n}\n");
+}
+
+#[test]
+fn summaries_of_successive_runs_are_added_up() {
+    let path = std::env::temp_dir().join("clippy_summary_json_merge_test.json");
+    let _ = fs::remove_file(&path);
+
+    run_on_fixture(&path);
+    assert_eq!(run_on_fixture(&path),
+               "{\n  \"double_neg\": { \"warn\": 4, \"deny\": 0 },\n  \"eq_op\": { \"warn\": 0, \"deny\": 2 }\n}\n");
+}
+
+#[test]
+fn summary_round_trips_through_json() {
+    let mut counts = BTreeMap::new();
+    counts.insert("eq_op".to_owned(), summary::LintCounts { warn: 3, deny: 1 });
+    counts.insert("needless_return".to_owned(), summary::LintCounts { warn: 0, deny: 12 });
+    assert_eq!(summary::from_json(&summary::to_json(&counts)), Some(counts));
+    assert_eq!(summary::from_json("{}\n"), Some(BTreeMap::new()));
+    assert_eq!(summary::from_json("not json"), None);
+}
@@ -0,0 +1,22 @@
+use std::process::Command;
+
+// The sysroot is baked in at compile time if clippy was built through rustup or multirust or with
+// `SYSROOT` set, in which case there is nothing to discover. Run this test with
+// `cargo test --test sysroot -- --ignored` from a build without those.
+#[test]
+#[ignore]
+fn missing_sysroot_is_reported() {
+    let output = Command::new("target/debug/cargo-clippy")
+        .arg("foo.rs")
+        // keep the rest of the environment, the dynamic libraries of the compiler still need to be found
+        .env("PATH", "")
+        .output()
+        .expect("could not run cargo-clippy");
+
+    assert_eq!(output.status.code(), Some(101));
+    let stderr = String::from_utf8(output.stderr).expect("stderr is not valid UTF-8");
+    assert!(stderr.contains("error: could not find the sysroot: `rustc` is not in your `PATH`"),
+            "unexpected output: {}",
+            stderr);
+    assert!(stderr.contains("https://rustup.rs"), "unexpected output: {}", stderr);
+}
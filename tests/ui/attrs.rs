@@ -1,7 +1,7 @@
 #![feature(plugin)]
 #![plugin(clippy)]
 
-#![deny(inline_always, deprecated_semver)]
+#![deny(inline_always, deprecated_semver, deprecated_semver_placeholder)]
 
 #[inline(always)]
 fn test_attr_lint() {
@@ -24,6 +24,23 @@ fn empty_and_false_positive_stmt() {
     unreachable!();
 }
 
+#[inline(always)]
+#[cold]
+fn cold_path(x: u32) -> u32 {
+    let y = x * 2;
+    y + 1
+}
+
+#[inline(always)]
+fn forwarder(x: u32) -> u32 {
+    cold_path(x)
+}
+
+#[inline(always)]
+fn not_a_forwarder(x: u32) -> u32 {
+    cold_path(x + 1)
+}
+
 #[deprecated(since = "forever")]
 pub const SOME_CONST : u8 = 42;
 
@@ -33,9 +50,16 @@ pub const ANOTHER_CONST : u8 = 23;
 #[deprecated(since = "0.1.1")]
 pub const YET_ANOTHER_CONST : u8 = 0;
 
+#[deprecated(since = "TBD")]
+pub const PLACEHOLDER_CONST : u8 = 1;
+
+#[deprecated(since = "1.2.3.4")]
+pub const TOO_MANY_PARTS_CONST : u8 = 2;
+
 fn main() {
     test_attr_lint();
     if false { false_positive_expr() }
     if false { false_positive_stmt() }
     if false { empty_and_false_positive_stmt() }
+    forwarder(not_a_forwarder(0));
 }
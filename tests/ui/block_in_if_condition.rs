@@ -89,5 +89,21 @@ fn condition_is_unsafe_block() {
     }
 }
 
+fn while_condition_has_block() {
+    let mut i = 0;
+    while {
+        i += 1;
+        i < 3
+    } {
+    }
+}
+
+fn while_condition_has_block_with_single_expression() {
+    let mut i = 0;
+    while { i < 3 } {
+        i += 1;
+    }
+}
+
 fn main() {
 }
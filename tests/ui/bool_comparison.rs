@@ -20,4 +20,20 @@ fn main() {
 
 
 
+    if x != true { "yes" } else { "no" };
+
+
+
+    if x != false { "yes" } else { "no" };
+
+
+
+    if true != x { "yes" } else { "no" };
+
+
+
+    if false != x { "yes" } else { "no" };
+
+
+
 }
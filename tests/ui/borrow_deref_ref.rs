@@ -0,0 +1,24 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(borrow_deref_ref)]
+#![allow(unused)]
+
+fn len(s: &str) -> usize {
+    s.len()
+}
+
+fn main() {
+    let s: &str = "foo";
+    let _ = len(&*s);
+    let _: &str = &*s;
+
+    // these are not the same as the original value
+    let mut x = 5;
+    let r = &mut x;
+    let _: &i32 = &*r;
+    let b = Box::new(5);
+    let _: &i32 = &*b;
+    let string = String::new();
+    let _: &str = &*string;
+}
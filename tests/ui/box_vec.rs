@@ -3,7 +3,7 @@
 
 #![deny(clippy)]
 #![allow(boxed_local, needless_pass_by_value)]
-#![allow(blacklisted_name)]
+#![allow(blacklisted_name, get_first_last)]
 
 macro_rules! boxit {
     ($init:expr, $x:ty) => {
@@ -0,0 +1,20 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(chained_and_then)]
+
+fn half(x: i32) -> Result<i32, ()> {
+    if x % 2 == 0 { Ok(x / 2) } else { Err(()) }
+}
+
+fn main() {
+    let x = Some(1);
+    let _ = x.and_then(|a| a.checked_add(1)).and_then(|b| b.checked_mul(2)).and_then(|c| c.checked_sub(3));
+
+    let r: Result<i32, ()> = Ok(8);
+    let _ = r.and_then(half).and_then(half);
+
+    // not chained
+    let _ = x.and_then(|a| a.checked_add(1));
+    let _ = x.and_then(|a| a.checked_add(1)).map(|b| b * 2).and_then(|c| c.checked_sub(3));
+}
@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(chars_count)]
+
+fn main() {
+    let s = "hello";
+    let t = String::from("wörld");
+
+    if s.chars().count() == t.len() {}
+    let _ = String::with_capacity(t.chars().count());
+    let mut v: Vec<u8> = Vec::new();
+    v.reserve(s.chars().count());
+
+    // not linted, the `char` count is used on its own
+    let _ = s.chars().count();
+    if t.chars().count() > 3 {}
+
+    // not linted, the length of a `Vec<char>` is a number of `char`s
+    let mut c: Vec<char> = Vec::with_capacity(s.chars().count());
+    c.reserve(t.chars().count());
+    if s.chars().count() == c.len() {}
+}
@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(chars_next_cmp)]
+
+fn main() {
+    let s = "hello";
+    let _ = s.chars().next().unwrap() == 'h';
+    let _ = 'h' != s.chars().next().unwrap();
+
+    let c = 'h';
+    let owned = String::from(s);
+    let _ = owned.chars().next().unwrap() == c;
+
+    // not the first char
+    let _ = s.chars().last().unwrap() == 'o';
+}
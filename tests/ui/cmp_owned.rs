@@ -24,4 +24,20 @@ fn main() {
     x != String::from("foo");
 
     42.to_string() == "42";
+
+    let y = String::from("oh");
+
+    x.to_string() == y.to_string();
+
+    String::from("foo") != x.to_owned();
+
+    42.to_string() == x.to_string();
+
+    let a: &[u8] = &[1, 2];
+    let b = vec![1, 2];
+    let c: &[u8] = &[1, 2];
+
+    b == a.to_vec();
+
+    a.to_vec() == c.to_vec();
 }
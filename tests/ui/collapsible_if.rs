@@ -180,4 +180,11 @@ fn main() {
     } else {
         assert!(true); // assert! is just an `if`
     }
+
+    // `if let` cannot be combined with a condition using `&&`
+    if x == "hello" {
+        if let Some(42) = Some(42) {
+            println!("Hello world!");
+        }
+    }
 }
@@ -0,0 +1,18 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(collect_len)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let v = vec![1, 2, 3, 2];
+
+    let _ = v.iter().filter(|&&x| x > 1).collect::<Vec<_>>().len();
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<i32>>().len();
+
+    // not linted, the `Vec` is used afterwards
+    let doubled = v.iter().map(|x| x * 2).collect::<Vec<_>>();
+    let _ = doubled.len();
+    // not linted, collecting into a set removes duplicates
+    let _ = v.iter().collect::<HashSet<_>>().len();
+}
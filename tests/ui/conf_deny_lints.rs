@@ -0,0 +1,12 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_deny_lints.toml"))]
+
+fn main() {
+    (0, 0).0 = 1;
+    allowed();
+}
+
+#[allow(temporary_assignment)]
+fn allowed() {
+    (0, 0).0 = 1;
+}
@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_deny_lints_direct.toml"))]
+
+// `double_parens` is not reported through Clippy's lint helpers
+fn parens() -> i32 {
+    ((0))
+}
+
+fn main() {
+    parens();
+}
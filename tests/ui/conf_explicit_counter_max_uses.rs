@@ -0,0 +1,25 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_explicit_counter_max_uses.toml"))]
+
+#![deny(explicit_counter_loop)]
+#![allow(unused)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut i = 0;
+    for x in &v {
+        println!("{}: {}", i, x);
+        i += 1;
+    }
+
+    // not linted, `j` is used twice besides the increment
+    let mut j = 0;
+    for x in &v {
+        println!("{}: {}", j, x);
+        if j % 2 == 0 {
+            println!("even");
+        }
+        j += 1;
+    }
+}
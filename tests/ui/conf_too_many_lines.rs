@@ -0,0 +1,31 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_too_many_lines.toml"))]
+
+#![deny(too_many_lines)]
+#![allow(unused)]
+
+fn just_under_the_limit() {
+    println!("1");
+    println!("2");
+    println!("3");
+    println!("4");
+}
+
+fn over_the_limit() {
+    println!("1");
+    println!("2");
+    println!("3");
+    println!("4");
+    println!("5");
+}
+
+// the signature does not count towards the limit
+fn long_signature(
+    a: u32,
+    b: u32,
+    c: u32,
+) -> u32 {
+    a + b + c
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_unit_arm_style_block.toml"))]
+
+#![deny(unit_arm_style)]
+#![allow(unused, single_match)]
+
+fn foo(_: u32) {}
+
+fn main() {
+    let x = Some(1);
+
+    match x {
+        Some(y) => foo(y),
+        None => {},
+    }
+
+    match x {
+        Some(y) => foo(y),
+        None => (),
+    }
+}
@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy(conf_file="./tests/auxiliary/conf_unreadable_literal.toml"))]
+
+#![deny(unreadable_literal)]
+
+fn main() {
+    let _ = 10000000;
+    let _ = 100000000;
+}
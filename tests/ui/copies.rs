@@ -404,6 +404,15 @@ fn ifs_same_cond() {
     else if a == 1 {
     }
 
+    if b {
+    }
+    else if a == 1 {
+    }
+    else if b {
+    }
+    else if b {
+    }
+
     // See #659
     if cfg!(feature = "feature1-659") {
         1
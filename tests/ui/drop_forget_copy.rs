@@ -59,4 +59,6 @@ fn main() {
     forget(a4);
     let a5 = a1.clone();
     forget(a5);
+
+    drop(5u32);
 }
@@ -0,0 +1,35 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(empty_if_branch)]
+
+fn foo() -> bool { true }
+
+fn main() {
+    if foo() {
+    } else {
+        println!("else");
+    }
+
+    if foo() {
+        println!("then");
+    } else {
+    }
+
+    if foo() {}
+
+    // not linted
+    if foo() {
+        // nothing to do yet
+    } else {
+        println!("else");
+    }
+    if foo() {
+        println!("then");
+    } else {
+        // nothing to do
+    }
+    if foo() {
+    } else if !foo() {
+        println!("else if");
+    }
+}
@@ -34,6 +34,14 @@ fn main() {
     // See #515
     let a: Option<Box<::std::ops::Deref<Target = [i32]>>> =
         Some(vec![1i32, 2]).map(|v| -> Box<::std::ops::Deref<Target = [i32]>> { Box::new(v) });
+
+    // not linted, the closure does not forward exactly its parameters
+    let other = 2u8;
+    let e = Some(1u8).map(|x| add(x, other));
+    let e = Some((1u8, 2u8)).map(|(x, y)| add(x, y));
+    meta2(|x, y| add(y, x));
+    let e = Some(1u8).map(|x| foo2(x + 1));
+    let e = Some(1u8).map(|x| x.count_ones());
 }
 
 fn meta<F>(f: F) where F: Fn(u8) {
@@ -64,3 +72,11 @@ fn divergent(_: u8) -> ! {
 fn generic<T>(_: T) -> u8 {
     0
 }
+
+fn add(x: u8, y: u8) -> u8 {
+    x + y
+}
+
+fn meta2<F>(f: F) where F: Fn(u8, u8) -> u8 {
+    f(1u8, 2u8);
+}
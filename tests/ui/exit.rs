@@ -0,0 +1,19 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(exit)]
+
+fn not_main(code: i32) {
+    if code != 0 {
+        std::process::exit(code);
+    }
+}
+
+fn main() {
+    not_main(0);
+
+    // not linted, exiting from `main` is fine
+    if false {
+        std::process::exit(1);
+    }
+    let _exit = || std::process::exit(2);
+}
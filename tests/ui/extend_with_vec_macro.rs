@@ -0,0 +1,20 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(extend_with_vec_macro)]
+
+use std::collections::HashSet;
+
+fn main() {
+    let mut v = vec![1, 2];
+    v.extend(vec![3, 4, 5]);
+    v.extend(vec![0; 3]);
+
+    let mut s = HashSet::new();
+    s.extend(vec![1, 2]);
+
+    // not linted, the elements are not `Copy` or this is not a `vec!`
+    let mut strings = vec![String::new()];
+    strings.extend(vec![String::new()]);
+    let w = vec![6];
+    v.extend(w);
+}
@@ -0,0 +1,42 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(for_kv_map)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    let h: HashMap<u8, u8> = HashMap::new();
+    for (k, _) in &h {
+        let _ = k;
+    }
+
+    let b: BTreeMap<u8, u8> = BTreeMap::new();
+    for (_, v) in &b {
+        let _ = v;
+    }
+
+    // not linted, not a map
+    let v: Vec<(u8, u8)> = Vec::new();
+    for (_, x) in &v {
+        let _ = x;
+    }
+
+    // not linted, only the std maps are matched, not any type named `HashMap`
+    let f = fake::HashMap(Vec::new());
+    for (k, _) in &f {
+        let _ = k;
+    }
+}
+
+mod fake {
+    pub struct HashMap(pub Vec<(u8, u8)>);
+
+    impl<'a> IntoIterator for &'a HashMap {
+        type Item = &'a (u8, u8);
+        type IntoIter = ::std::slice::Iter<'a, (u8, u8)>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.iter()
+        }
+    }
+}
@@ -89,7 +89,7 @@ impl Unrelated {
 
 #[deny(needless_range_loop, explicit_iter_loop, explicit_into_iter_loop, iter_next_loop, reverse_range_loop, explicit_counter_loop, for_kv_map)]
 #[deny(unused_collect)]
-#[allow(linkedlist, shadow_unrelated, unnecessary_mut_passed, cyclomatic_complexity, similar_names)]
+#[allow(linkedlist, shadow_unrelated, unnecessary_mut_passed, cyclomatic_complexity, similar_names, into_iter_on_array)]
 #[allow(many_single_char_names)]
 fn main() {
     const MAX_LEN: usize = 42;
@@ -4,6 +4,7 @@
 
 fn main() {
     format!("foo");
+    format!("");
 
     format!("{}", "foo");
     format!("{:?}", "foo"); // we only want to warn about `{}`
@@ -17,11 +18,13 @@ fn main() {
     format!("{:+}", arg); // we only want to warn about `{}`
     format!("foo {}", arg);
     format!("{} bar", arg);
+    format!("{}", arg.len() + 1);
 
-    // we don’t want to warn for non-string args, see #697
     format!("{}", 42);
-    format!("{:?}", 42);
-    format!("{:+}", 42);
+    format!("{:?}", 42); // we only want to warn about `{}`
+    format!("{:+}", 42); // we only want to warn about `{}`
+    format!("{:>5}", 42); // width and precision change the output
+    format!("{:.2}", 1.0);
     format!("foo {}", 42);
     format!("{} bar", 42);
 
@@ -0,0 +1,23 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(get_first_last)]
+#![allow(get_unwrap)]
+
+use std::collections::HashMap;
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let s: &[u32] = &[1, 2, 3];
+
+    let _ = v.get(0);
+    let _ = s.get(0);
+    let _ = v.get(v.len() - 1);
+
+    // not linted
+    let _ = v.get(1);
+    let _ = v.get(s.len() - 1);
+    let _ = v.get(0).unwrap();
+    let mut m = HashMap::new();
+    m.insert(0, 1);
+    let _ = m.get(&0);
+}
@@ -0,0 +1,29 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(hash_collect_loop)]
+#![allow(unused)]
+
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    let v = vec![1, 2, 2, 3];
+
+    for x in v.iter().collect::<HashSet<_>>() {
+        println!("{}", x);
+    }
+
+    for (k, w) in v.iter().map(|&x| (x, x * 2)).collect::<HashMap<_, _>>() {
+        println!("{} {}", k, w);
+    }
+
+    // not linted, the collection is not a set
+    for x in v.iter().collect::<Vec<_>>() {
+        println!("{}", x);
+    }
+
+    // not linted, the set is used after the loop
+    let set: HashSet<_> = v.iter().collect();
+    for x in &set {
+        println!("{}", x);
+    }
+}
@@ -0,0 +1,54 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(hash_without_capacity)]
+#![allow(unused_variables)]
+
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut map = HashMap::new();
+    for x in &v {
+        map.insert(*x, x * 2);
+    }
+
+    let mut set = HashSet::new();
+    for x in &[4, 5] {
+        let y = x + 1;
+        set.insert(y);
+    }
+
+    // not a collection of known length
+    let mut map = HashMap::new();
+    for x in 0..10 {
+        map.insert(x, x);
+    }
+
+    // may skip the insertion
+    let mut set = HashSet::new();
+    for x in &v {
+        if *x == 2 {
+            continue;
+        }
+        set.insert(*x);
+    }
+
+    // conditional insertion
+    let mut set = HashSet::new();
+    for x in &v {
+        if *x > 1 {
+            set.insert(*x);
+        }
+    }
+
+    // a `break` from an inner loop does not skip the insertion
+    let mut set = HashSet::new();
+    for x in &v {
+        loop {
+            break;
+        }
+        set.insert(*x);
+    }
+}
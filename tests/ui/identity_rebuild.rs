@@ -0,0 +1,19 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(identity_rebuild)]
+#![allow(unused_assignments)]
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    v = v.clone();
+    v = v.iter().cloned().collect();
+    v = v.into_iter().collect();
+
+    // not identical
+    let w = vec![4, 5, 6];
+    v = w.clone();
+    v = v.iter().map(|x| x * 2).collect();
+    v = v.iter().cloned().filter(|&x| x > 1).collect();
+    println!("{:?}", v);
+}
@@ -48,6 +48,9 @@ fn main() {
     if let Ok(x) = Ok::<i32,i32>(42) {
         println!("{}", x);
     }
+
+    let r: &Result<i32, i32> = &Ok(42);
+    if let Ok(_) = *r {}
 }
 
 
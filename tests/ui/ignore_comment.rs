@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(eq_op, zero_divided_by_zero)]
+#![allow(unused)]
+
+fn main() {
+    // clippy:ignore eq_op
+    let a = 1 == 1;
+
+    // clippy:ignore eq_op, zero_divided_by_zero
+    let b = 0.0 / 0.0;
+
+    // clippy:ignore zero_divided_by_zero
+    let c = 2 == 2;
+
+    let d = 3 == 3;
+}
@@ -0,0 +1,18 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(needless_pass_by_value)]
+#![allow(dead_code)]
+
+// `needless_pass_by_value` caches its `implements_trait` queries while checking a function, debug
+// builds check that cached answers agree with evaluated ones for arguments of the same type.
+
+fn strings(a: String, b: String, c: String) {
+    assert_eq!(a.len() + b.len(), c.len());
+}
+
+fn closures<F: Fn()>(f: F, g: F) {
+    f();
+    g();
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(inconsistent_digit_grouping)]
+
+fn main() {
+    let _ = 1_00_000;
+    let _ = 1_000_0u32;
+    let _ = 1_000.123_4_5;
+    let _ = 0xD_EAD_BEEFu32;
+
+    // not linted
+    let _ = 100_000;
+    let _ = 1_000_000_u32;
+    let _ = 1.000_1;
+    let _ = 0xFF_FF_FF;
+    let _ = 0x1_0000_0000u64;
+}
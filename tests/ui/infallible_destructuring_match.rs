@@ -0,0 +1,43 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(infallible_destructuring_match)]
+#![allow(dead_code)]
+
+enum SingleVariantEnum {
+    Variant(i32),
+}
+
+struct TupleStruct(i32);
+
+fn main() {
+    let wrapper = SingleVariantEnum::Variant(0);
+
+    match wrapper {
+        SingleVariantEnum::Variant(i) => println!("{}", i),
+    }
+
+    let data = TupleStruct(0);
+
+    match data {
+        TupleStruct(i) => println!("{}", i),
+    }
+
+    // refutable, not linted
+    let option = Some(0);
+
+    match option {
+        Some(i) => println!("{}", i),
+        None => (),
+    }
+
+    // guards can fail
+    match data {
+        TupleStruct(i) if i > 0 => println!("{}", i),
+        TupleStruct(_) => (),
+    }
+
+    // the value of the match is used, so there is no suggestion
+    let _ = match data {
+        TupleStruct(i) => i,
+    };
+}
@@ -0,0 +1,15 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(into_iter_on_array)]
+
+fn main() {
+    let arr = [1, 2, 3];
+    let _: Vec<&i32> = arr.into_iter().collect();
+    let _: Vec<&i32> = (&arr).into_iter().collect();
+    let _ = [4, 5].into_iter().count();
+
+    // not linted, a reference is expected to yield references
+    let r = &arr;
+    let _: Vec<&i32> = r.into_iter().collect();
+    let _: Vec<i32> = vec![1, 2].into_iter().collect();
+}
@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(iter_len_zero)]
+#![allow(len_zero)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    if v.iter().map(|x| x + 1).len() > 0 {}
+    if (0..10).len() == 0 {}
+
+    // not linted, collections are handled by `len_zero`
+    if v.len() != 0 {}
+    // not linted, not compared to zero
+    if v.iter().len() > 1 {}
+}
@@ -189,4 +189,12 @@ fn main() {
 fn test_slice(b: &[u8]) {
     if b.len() != 0 {
     }
+    if b.len() >= 1 {
+    }
+    if 1 <= b.len() {
+    }
+    if b.len() <= 1 { // no error, this is not an emptiness check
+    }
+    if 1 >= b.len() { // no error, this is not an emptiness check
+    }
 }
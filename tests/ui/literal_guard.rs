@@ -0,0 +1,35 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(literal_guard)]
+
+fn main() {
+    let x = 42;
+    match x {
+        n if n == 5 => println!("five"),
+        n if 6 == n => println!("{}", n),
+        _ => (),
+    }
+
+    let s = "foo";
+    match s {
+        s if s == "bar" => (),
+        _ => (),
+    }
+
+    // not linted
+    let f = 1.5;
+    match f {
+        f if f == 1.0 => (),
+        _ => (),
+    }
+    let owned = String::new();
+    match owned {
+        ref o if o == "foo" => (),
+        _ => (),
+    }
+    match x {
+        n if n == x + 1 => (),
+        n if n > 5 => (),
+        _ => (),
+    }
+}
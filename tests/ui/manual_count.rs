@@ -0,0 +1,25 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(manual_count)]
+#![allow(explicit_counter_loop)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    let mut count: usize = 0;
+    for _ in v.iter().filter(|x| **x > 1) {
+        count += 1;
+    }
+
+    let mut n = 0usize;
+    for _ in &v { n += 1 }
+
+    // not linted, the counter is used in the body
+    let mut i: usize = 0;
+    for x in &v {
+        println!("{} {}", i, x);
+        i += 1;
+    }
+
+    println!("{} {} {}", count, n, i);
+}
@@ -0,0 +1,62 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(manual_flatten)]
+
+fn main() {
+    let v = vec![Some(1), None, Some(3)];
+    for x in v {
+        if let Some(y) = x {
+            println!("{}", y);
+        }
+    }
+
+    let v = vec![Some(1), None, Some(3)];
+    for x in v.iter().cloned() {
+        if let Some(y) = x {
+            println!("{}", y);
+        };
+    }
+
+    // not the whole body
+    let v = vec![Some(1), None, Some(3)];
+    for x in v {
+        println!("next");
+        if let Some(y) = x {
+            println!("{}", y);
+        }
+    }
+
+    // `else` branch
+    let v = vec![Some(1), None, Some(3)];
+    for x in v {
+        if let Some(y) = x {
+            println!("{}", y);
+        } else {
+            println!("none");
+        }
+    }
+
+    // loop variable used in the body
+    let v = vec![Some(1), None, Some(3)];
+    for x in v.iter().cloned() {
+        if let Some(y) = x {
+            println!("{} {:?}", y, x);
+        }
+    }
+
+    // scrutinee is not the loop variable
+    let v = vec![1, 2, 3];
+    for x in v {
+        if let Some(y) = Some(x) {
+            println!("{}", y);
+        }
+    }
+
+    // not an `Option`
+    let v: Vec<Result<i32, ()>> = vec![Ok(1), Err(())];
+    for x in v {
+        if let Ok(y) = x {
+            println!("{}", y);
+        }
+    }
+}
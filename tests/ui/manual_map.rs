@@ -0,0 +1,48 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(manual_map)]
+
+fn main() {
+    let x = Some(3);
+
+    let _ = match x {
+        Some(y) => Some(y + 1),
+        None => None,
+    };
+
+    let _ = match x {
+        None => None,
+        Some(y) => Some(y.to_string()),
+    };
+
+    let _ = match x {
+        Some(y) => { Some(y * 2) },
+        None => { None },
+    };
+
+    // not linted, the `None` arm returns something else
+    let _ = match x {
+        Some(y) => Some(y + 1),
+        None => Some(0),
+    };
+
+    // not linted, guarded arm
+    let _ = match x {
+        Some(y) if y > 0 => Some(y + 1),
+        _ => None,
+    };
+
+    // not linted, nothing is mapped
+    let _ = match x {
+        Some(y) => Some(y),
+        None => None,
+    };
+}
+
+fn early_return(x: Option<u32>) -> Option<u32> {
+    // not linted, the `return` can't be moved into a closure
+    match x {
+        Some(y) => Some(if y == 0 { return Some(1) } else { y }),
+        None => None,
+    }
+}
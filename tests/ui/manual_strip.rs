@@ -0,0 +1,25 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(manual_strip)]
+#![allow(unused)]
+
+fn main() {
+    let s = "hello world";
+    let prefix = "hello ";
+    if s.starts_with(prefix) {
+        let _ = &s[prefix.len()..];
+    }
+
+    let owned = String::from(s);
+    if owned.starts_with("hello") {
+        println!("{}", &owned["hello".len()..]);
+    }
+
+    // the slice doesn't match the checked prefix
+    if s.starts_with(prefix) {
+        let _ = &s["hello".len()..];
+        let _ = &owned[prefix.len()..];
+        let _ = &s[..prefix.len()];
+    }
+}
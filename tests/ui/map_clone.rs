@@ -56,6 +56,10 @@ fn not_linted_option() {
 
     // Not linted: multiple dereferences
     let _: Option<(i32, i32)> = x.as_ref().as_ref().map(|&&x| x);
+
+    // Not linted: cloning owned values
+    let x = Some(String::new());
+    x.map(|y| y.clone());
 }
 
 #[derive(Copy, Clone)]
@@ -0,0 +1,55 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(match_question_mark)]
+#![allow(unused)]
+
+#[derive(Debug)]
+struct Error;
+
+impl From<()> for Error {
+    fn from(_: ()) -> Error {
+        Error
+    }
+}
+
+fn foo() -> Result<u32, ()> {
+    Ok(42)
+}
+
+fn same_error() -> Result<u32, ()> {
+    let x = match foo() {
+        Ok(x) => x,
+        Err(e) => return Err(e),
+    };
+    Ok(x)
+}
+
+fn converted_error() -> Result<u32, Error> {
+    let x = match foo() {
+        Ok(x) => x,
+        Err(e) => return Err(e.into()),
+    };
+    Ok(x)
+}
+
+fn not_propagated() -> Result<u32, Error> {
+    let x = match foo() {
+        Ok(x) => x + 1,
+        Err(e) => return Err(e.into()),
+    };
+    let y = match foo() {
+        Ok(y) => y,
+        Err(_) => return Err(Error),
+    };
+    Ok(x + y)
+}
+
+fn not_result() -> Option<u32> {
+    match foo() {
+        Ok(x) => Some(x),
+        Err(_) => None,
+    }
+}
+
+fn main() {}
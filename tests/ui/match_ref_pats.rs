@@ -0,0 +1,52 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(match_ref_pats)]
+#![allow(unused, single_match, borrow_deref_ref)]
+
+enum Shape {
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+    Empty,
+}
+
+fn variants(s: &Shape) -> u32 {
+    match s {
+        &Shape::Circle(r) => r,
+        &Shape::Rect { w, h } => w * h,
+        &Shape::Empty => 0,
+    }
+}
+
+fn variants_with_wild(s: &Shape) -> u32 {
+    match s {
+        &Shape::Rect { w, .. } => w,
+        _ => 0,
+    }
+}
+
+fn both(s: &Shape) -> u32 {
+    match &*s {
+        &Shape::Circle(r) => r,
+        &Shape::Rect { w, h } => w * h,
+        &Shape::Empty => 0,
+    }
+}
+
+fn both_mut(s: &mut Shape) {
+    match &mut *s {
+        &mut Shape::Circle(ref mut r) => *r += 1,
+        &mut Shape::Rect { ref mut w, .. } => *w += 1,
+        &mut Shape::Empty => (),
+    }
+}
+
+fn binding(s: &Shape) -> u32 {
+    // this doesn't trigger, a binding is not a `&`-pattern
+    match s {
+        &Shape::Circle(r) => r,
+        other => 0,
+    }
+}
+
+fn main() {}
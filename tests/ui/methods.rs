@@ -195,7 +195,7 @@ fn filter_next() {
     // check single-line case
     let _ = v.iter().filter(|&x| *x < 0).next();
 
-
+    let _ = v.iter().filter(|&x| *x < 0).next().is_some();
 
     // check multi-line case
     let _ = v.iter().filter(|&x| {
@@ -696,4 +696,9 @@ fn iter_clone_collect() {
 
     let v3 : HashSet<isize> = v.iter().cloned().collect();
     let v4 : VecDeque<isize> = v.iter().cloned().collect();
+
+    let original = vec![1, 2, 3];
+    let copy : Vec<isize> = original.iter().cloned().collect();
+    let slice : &[isize] = &original;
+    let from_slice : Vec<isize> = slice.iter().cloned().collect();
 }
@@ -0,0 +1,26 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(useless_attribute)]
+#![allow(dead_code, unused_attributes)]
+
+#[path = "foo.rs"]
+fn foo() {}
+
+#[path = "bar.rs"]
+struct Bar;
+
+// `#[path]` is fine on inline modules
+#[path = "baz"]
+mod baz {}
+
+#[no_mangle]
+fn mangled() {}
+
+#[no_mangle]
+pub extern "C" fn not_mangled() {}
+
+// public functions can still be called by Rust code linking to the crate
+#[no_mangle]
+pub fn exported() {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(needless_as_str)]
+
+fn main() {
+    let s = String::from("foo");
+    let _ = s.as_str().len();
+    let _ = s.as_str().starts_with("fo");
+    let v = vec![1, 2, 3];
+    let _ = v.as_slice().first();
+
+    // methods which are not checked
+    let _ = s.as_str().to_owned();
+    let _ = v.as_slice().to_vec();
+}
@@ -72,3 +72,17 @@ fn bool_ret6(x: bool, y: bool) -> bool {
 
 
 }
+
+#[allow(needless_return, dead_code)]
+fn bool_ret7(x: bool) -> bool {
+    if x { return true; }
+    return false;
+}
+
+#[allow(needless_return, dead_code)]
+fn bool_ret8(x: bool) -> bool {
+    if x {
+        return false;
+    }
+    return true;
+}
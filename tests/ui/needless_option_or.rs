@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(needless_option_or)]
+#![allow(or_fun_call)]
+
+fn expensive() -> i32 {
+    42
+}
+
+fn main() {
+    let opt = Some(1);
+
+    let _ = opt.or(Some(0)).unwrap();
+    let _ = opt.or(Some(expensive())).unwrap();
+    let _ = opt.or(None);
+
+    // not linted
+    let _ = opt.or(Some(0));
+    let _ = opt.or(Some(0)).unwrap_or(2);
+    let res: Result<i32, i32> = Ok(1);
+    let _ = res.or(Ok(0)).unwrap();
+}
@@ -0,0 +1,35 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(needless_range_loop)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    for i in 0..(&v).len() {
+        println!("{}", v[i]);
+    }
+
+    for i in 1..(&v).len() {
+        println!("{}", v[i]);
+    }
+
+    // `.take()` is still needed for the length of another `Vec`
+    let w = vec![1, 2];
+    for i in 0..w.len() {
+        println!("{}", v[i]);
+    }
+
+    Test { inner: vec![1] }.print();
+}
+
+struct Test {
+    inner: Vec<u8>,
+}
+
+impl Test {
+    fn print(&self) {
+        for i in 0..self.inner.len() {
+            println!("{}", self.inner[i]);
+        }
+    }
+}
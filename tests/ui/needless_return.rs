@@ -64,10 +64,28 @@ fn test_closure() {
 
 }
 
+fn test_if_let(x: Option<bool>) -> bool {
+    if let Some(x) = x {
+        return x;
+    } else {
+        return false;
+    }
+}
+
+macro_rules! return_true {
+    () => { return true; }
+}
+
+fn test_macro() -> bool {
+    return_true!();
+}
+
 fn main() {
     let _ = test_end_of_fn();
     let _ = test_no_semicolon();
     let _ = test_if_block();
     let _ = test_match(true);
     test_closure();
+    let _ = test_if_let(None);
+    let _ = test_macro();
 }
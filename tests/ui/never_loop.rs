@@ -26,6 +26,30 @@ fn main() {
         break;
     }
 
+    loop {
+        // not linted, the loop can continue
+        if x == 1 {
+            continue;
+        }
+        break;
+    }
+
+    'outer: loop {
+        // not linted, the inner loop continues the outer one
+        for _ in 0..x {
+            continue 'outer;
+        }
+        break;
+    }
+
+    loop {
+        for _ in 0..x {
+            // this only continues the inner loop
+            continue;
+        }
+        break;
+    }
+
     loop {
         loop {
             if x == 1 { return; }
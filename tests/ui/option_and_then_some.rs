@@ -0,0 +1,42 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(option_and_then_some)]
+#![allow(unused)]
+
+fn compute(x: u32) -> Option<u32> {
+    if x > 10 { None } else { Some(x * 2) }
+}
+
+fn main() {
+    let x = Some(5);
+
+    let _ = x.and_then(|v| Some(v + 1));
+    let _ = x.and_then(|v| compute(v).map(|w| w).or(Some(0)));
+    let _ = x.and_then(|v| if v > 2 { Some(v) } else { Some(0) });
+
+    // genuinely optional, not linted
+    let _ = x.and_then(compute);
+    let _ = x.and_then(|v| compute(v));
+    let _ = x.and_then(|v| compute(v).or(compute(v + 1)));
+    let _ = x.and_then(|v| if v > 2 { Some(v) } else { None });
+    let _ = x.and_then(|v| {
+        if v == 0 {
+            return None;
+        }
+        Some(v)
+    });
+    let _ = x.and_then(|v| Some(match compute(v) { Some(w) => w, None => return None }));
+    let _ = x.and_then(|v| match compute(v) { Some(w) => w.checked_add(1).or(Some(0)), None => None });
+    let _ = x.and_then(|v| match compute(v) {
+        Some(0) => Some(1),
+        w => w,
+    });
+
+    // `return`s in nested closures don't count
+    let _ = x.and_then(|v| Some(compute(v).map_or(0, |w| {
+        if w > 1 {
+            return 1;
+        }
+        w
+    })));
+}
@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(option_map_unwrap_or, option_map_unwrap_or_else)]
+#![allow(unused)]
+
+fn default() -> i32 {
+    println!("evaluated eagerly");
+    0
+}
+
+fn main() {
+    let opt = Some(1);
+    let _ = opt.map(|x| x + 1).unwrap_or(0);
+    // `map_or` evaluates its default eagerly too, so the side effect is kept
+    let _ = opt.map(|x| x + 1).unwrap_or(default());
+    let _ = opt.map(|x| x + 1).unwrap_or_else(default);
+
+    // not linted, `Result` has neither `map_or` nor `map_or_else`
+    let res: Result<i32, ()> = Ok(1);
+    let _ = res.map(|x| x + 1).unwrap_or(0);
+    let _ = res.map(|x| x + 1).unwrap_or_else(|_| 0);
+}
@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(or_insert_with_default)]
+#![allow(unused)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn main() {
+    let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    map.entry(1).or_insert_with(Vec::new).push(1);
+    map.entry(2).or_insert_with(|| Vec::new()).push(2);
+    map.entry(3).or_insert_with(Default::default).push(3);
+
+    let mut btree: BTreeMap<u32, String> = BTreeMap::new();
+    btree.entry(1).or_insert_with(String::new).push('a');
+
+    // not equivalent to `Default::default`
+    map.entry(4).or_insert_with(|| vec![1]).push(4);
+    map.entry(5).or_insert_with(|| Vec::with_capacity(10)).push(5);
+    map.entry(6).or_insert(Vec::new()).push(6);
+}
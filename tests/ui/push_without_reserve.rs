@@ -0,0 +1,48 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(push_without_reserve)]
+#![allow(unused, vec_init_then_push)]
+
+fn main() {
+    let mut v = Vec::new();
+    for i in 0..10 {
+        v.push(i);
+    }
+
+    let mut w = Vec::new();
+    for i in 0..5 {
+        let x = i * 2;
+        w.push(x);
+    }
+
+    // not linted, the `Vec` is used before the loop
+    let mut v = Vec::new();
+    v.push(42);
+    for i in 0..10 {
+        v.push(i);
+    }
+
+    // not linted, the push is conditional
+    let mut v = Vec::new();
+    for i in 0..10 {
+        if i % 2 == 0 {
+            v.push(i);
+        }
+    }
+
+    // not linted, the bounds are not constant
+    let n = v.len();
+    let mut v = Vec::new();
+    for i in 0..n {
+        v.push(i);
+    }
+
+    // not linted, some iterations do not push
+    let mut v = Vec::new();
+    for i in 0..10 {
+        if i % 3 == 0 {
+            continue;
+        }
+        v.push(i);
+    }
+}
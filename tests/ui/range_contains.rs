@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(manual_range_contains)]
+#![allow(unused)]
+
+fn main() {
+    let x = 5;
+
+    let _ = x >= 8 && x < 12;
+    let _ = x < 12 && x >= 8;
+    let _ = x >= 8 && x <= 12;
+    let _ = 8 <= x && 12 >= x;
+
+    // not linted
+    let _ = x > 8 && x < 12;
+    let _ = x >= 8 && x > 12;
+}
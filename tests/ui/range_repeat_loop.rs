@@ -0,0 +1,29 @@
+#![feature(plugin, inclusive_range_syntax)]
+#![plugin(clippy)]
+#![deny(range_repeat_loop)]
+#![allow(unused_variables)]
+
+fn main() {
+    let n = 10;
+
+    for _ in 0..n {
+        println!("hello");
+    }
+    for _i in 0..3 {
+        println!("hello");
+    }
+    for i in 0..n {
+        println!("hello");
+    }
+
+    // not linted
+    for i in 0..n {
+        println!("{}", i);
+    }
+    for _ in 1..n {
+        println!("hello");
+    }
+    for _ in 0...n {
+        println!("hello");
+    }
+}
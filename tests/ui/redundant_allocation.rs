@@ -0,0 +1,21 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(box_vec, redundant_allocation)]
+#![allow(dead_code)]
+
+use std::rc::Rc;
+
+struct Foo {
+    values: Box<Vec<u8>>,
+    name: Box<String>,
+    shared: Rc<Box<u8>>,
+
+    // not linted
+    boxed: Box<u8>,
+    shared_values: Rc<Vec<u8>>,
+    shared_trait: Rc<Box<Fn()>>,
+    shared_slice: Rc<Box<[u8]>>,
+    shared_str: Rc<Box<str>>,
+}
+
+fn main() {}
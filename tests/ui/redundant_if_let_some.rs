@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(redundant_if_let_some)]
+#![allow(unused, if_let_redundant_pattern_matching)]
+
+fn main() {
+    let opt = Some(42);
+    let res = Ok::<i32, i32>(42);
+
+    let _ = if let Some(_) = opt { true } else { false };
+    let _ = if let Some(_) = opt { false } else { true };
+    let _ = if let None = opt { true } else { false };
+    let _ = if let Ok(_) = res { true } else { false };
+    let _ = if let Ok(_) = res { false } else { true };
+    let _ = if let Err(_) = res { true } else { false };
+
+    // these are fine
+    let _ = if let Some(x) = opt { x > 0 } else { false };
+    let _ = if let Some(_) = opt { true } else { true };
+    let _ = opt.is_some();
+}
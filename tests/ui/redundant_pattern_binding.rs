@@ -0,0 +1,27 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(redundant_pattern_binding)]
+#![allow(unused_variables)]
+
+fn main() {
+    let x = Some(5);
+
+    match x {
+        Some(y @ 1...9) => println!("digit"),
+        z @ _ => println!("other"),
+    }
+
+    // not linted, the bindings are used
+    match x {
+        Some(y @ 1...9) => println!("{}", y),
+        Some(y @ 10...99) if y % 2 == 0 => println!("even"),
+        Some(y @ 100...999) | Some(y @ 1000...9999) => println!("{}", y),
+        _ => (),
+    }
+
+    // not linted, underscore-prefixed bindings are intentionally unused
+    match x {
+        Some(_y @ 1...9) => println!("digit"),
+        _ => (),
+    }
+}
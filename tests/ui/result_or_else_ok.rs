@@ -0,0 +1,26 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(result_or_else_ok)]
+#![allow(unused)]
+
+fn recover(e: &str) -> Result<u32, ()> {
+    if e.is_empty() { Err(()) } else { Ok(e.len() as u32) }
+}
+
+fn main() {
+    let x: Result<u32, &str> = Ok(5);
+
+    let _: Result<u32, ()> = x.or_else(|_| Ok(0));
+    let _: Result<u32, ()> = x.or_else(|e| if e.is_empty() { Ok(0) } else { Ok(1) });
+
+    // genuine recovery, not linted
+    let _ = x.or_else(recover);
+    let _ = x.or_else(|e| recover(e));
+    let _ = x.or_else(|e| if e.is_empty() { Ok(0) } else { Err(()) });
+    let _: Result<u32, ()> = x.or_else(|e| {
+        if e.is_empty() {
+            return Err(());
+        }
+        Ok(1)
+    });
+}
@@ -0,0 +1,59 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(same_item_push)]
+#![allow(unused)]
+
+fn main() {
+    let mut v = Vec::new();
+    for _ in 0..10 {
+        v.push(0u8);
+    }
+
+    let item = 2;
+    let mut w = Vec::new();
+    for _ in 0..5 {
+        w.push(item);
+    }
+
+    // not linted, the item depends on the loop variable
+    let mut v = Vec::new();
+    for i in 0..10 {
+        v.push(i);
+    }
+
+    // not linted, `vec!` would evaluate the call only once
+    let mut v = Vec::new();
+    for _ in 0..10 {
+        v.push(String::new());
+    }
+
+    // the `Vec` is not empty
+    let mut v = vec![1u8];
+    for _ in 0..10 {
+        v.push(0u8);
+    }
+
+    // the number of iterations is not constant
+    let n = v.len();
+    let mut v = Vec::new();
+    for _ in 0..n {
+        v.push(0u8);
+    }
+    for _ in 0..n {
+        v.push(1u8);
+    }
+
+    // not linted, the loop does more than pushing
+    let mut v = Vec::new();
+    for _ in 0..10 {
+        println!("pushing");
+        v.push(0u8);
+    }
+
+    // the number of iterations is not a `usize`
+    let m = 3u32;
+    let mut v = Vec::new();
+    for _ in 0..m {
+        v.push(0u8);
+    }
+}
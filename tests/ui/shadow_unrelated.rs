@@ -0,0 +1,11 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(shadow_unrelated)]
+
+fn main() {
+    let s = " foo ";
+    // not linted, refines the previous binding
+    let s = s.trim();
+    let s = "bar";
+    println!("{}", s);
+}
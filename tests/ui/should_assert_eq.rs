@@ -1,7 +1,7 @@
 #![feature(plugin)]
 #![plugin(clippy)]
 
-#![allow(needless_pass_by_value)]
+#![allow(needless_pass_by_value, dead_code)]
 #![deny(should_assert_eq)]
 
 #[derive(PartialEq, Eq)]
@@ -27,3 +27,8 @@ fn test_generic<T: std::fmt::Debug + Eq, U: Eq>(x: T, y: T, z: U, w: U) {
     assert!(x != y);
     assert!(z != w); // ok
 }
+
+fn test_message(x: i32, y: i32) {
+    assert!(x == y, "x and y differ");
+    assert!(x != y, "{} and {} are equal", x, y);
+}
@@ -0,0 +1,22 @@
+// compile-flags: --test
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(should_panic_without_expected)]
+
+#[test]
+#[should_panic]
+fn bare() {
+    panic!("boom");
+}
+
+#[test]
+#[should_panic(expected = "boom")]
+fn with_expected() {
+    panic!("boom");
+}
+
+#[test]
+#[should_panic = "boom"]
+fn with_shorthand() {
+    panic!("boom");
+}
@@ -0,0 +1,32 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(single_match, match_bool)]
+
+macro_rules! print_some {
+    ($e:expr) => {
+        match $e {
+            Some(x) => println!("{}", x),
+            _ => (),
+        }
+    };
+}
+
+macro_rules! if_true {
+    ($c:expr, $e:expr) => {
+        match $c {
+            true => $e,
+            false => (),
+        }
+    };
+}
+
+fn main() {
+    // not linted, the matches come from a macro expansion
+    print_some!(Some(1));
+    if_true!(1 > 0, println!("yes"));
+
+    match Some(1) {
+        Some(x) => println!("{}", x),
+        _ => (),
+    }
+}
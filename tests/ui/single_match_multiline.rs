@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(single_match)]
+
+fn main() {
+    let x = Some(1);
+    if x.is_some() {
+        match x {
+            Some(y) => {
+                println!("{}", y);
+                println!("{}", y + 1);
+            },
+            _ => (),
+        }
+    }
+}
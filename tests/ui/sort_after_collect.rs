@@ -0,0 +1,27 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![feature(sort_unstable)]
+#![deny(sort_after_collect)]
+
+fn main() {
+    let mut v: Vec<_> = (0..10).rev().collect();
+    v.sort();
+
+    // elements with equal keys can be told apart
+    let mut w: Vec<(i32, i32)> = v.iter().map(|&x| (x % 3, x)).collect();
+    w.sort_by_key(|&(k, _)| k);
+
+    // mutated between the `collect` and the sort
+    let mut v: Vec<_> = (0..10).rev().collect();
+    v.push(42);
+    v.sort();
+
+    // already unstable
+    let mut v: Vec<_> = (0..10).rev().collect();
+    v.sort_unstable();
+
+    // sorting another `Vec`
+    let mut v: Vec<_> = (0..10).rev().collect();
+    w.sort();
+    v.sort();
+}
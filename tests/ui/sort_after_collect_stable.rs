@@ -0,0 +1,9 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(sort_after_collect)]
+
+// not linted, `sort_unstable` is not available without its feature gate
+fn main() {
+    let mut v: Vec<_> = (0..10).rev().collect();
+    v.sort();
+}
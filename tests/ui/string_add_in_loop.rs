@@ -0,0 +1,35 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(string_add_in_loop)]
+
+fn main() {
+    let mut s = String::new();
+    for x in 0..10 {
+        s += &x.to_string();
+    }
+
+    for x in vec!["a", "b"] {
+        s = s + x;
+    }
+
+    // only linted once, for the inner loop
+    for _ in 0..2 {
+        for x in vec!["a", "b"] {
+            s += x;
+        }
+    }
+
+    // not linted, numeric accumulation
+    let mut n = 0;
+    for x in 0..10 {
+        n += x;
+    }
+
+    // not linted, not accumulating into the same `String`
+    let mut t = String::new();
+    for x in vec!["a", "b"] {
+        t = s.clone() + x;
+    }
+
+    println!("{} {} {}", s, t, n);
+}
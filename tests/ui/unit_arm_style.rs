@@ -0,0 +1,28 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(unit_arm_style)]
+#![allow(unused, single_match)]
+
+fn foo(_: u32) {}
+
+fn main() {
+    let x = Some(1);
+
+    match x {
+        Some(y) => foo(y),
+        None => {},
+    }
+
+    match x {
+        Some(y) => foo(y),
+        None => (),
+    }
+
+    match x {
+        Some(y) => foo(y),
+        None => {
+            // nothing to do
+        },
+    }
+}
@@ -0,0 +1,44 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(unnecessary_unwrap)]
+#![allow(unused)]
+
+fn main() {
+    let x = Some(1);
+    if x.is_some() {
+        println!("{}", x.unwrap());
+    }
+
+    let r: Result<u8, ()> = Ok(1);
+    if r.is_ok() {
+        let _ = r.unwrap() + 1;
+    }
+
+    if x.is_none() {
+        println!("none");
+    } else {
+        let _ = x.unwrap();
+    }
+
+    // the `unwrap` might fail here
+    if x.is_none() {
+        let _ = x.unwrap();
+    }
+
+    let y = Some(2);
+    if x.is_some() {
+        let _ = y.unwrap();
+    }
+
+    // `z` is mutated before being unwrapped
+    let mut z = Some(1);
+    if z.is_some() {
+        z.take();
+        let _ = z.unwrap();
+    }
+    if z.is_some() {
+        z = None;
+        let _ = z.unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(unreachable_match_arm)]
+#![allow(unreachable_patterns, single_match, literal_guard)]
+
+fn main() {
+    let x = Some(3);
+    match x {
+        Some(y) if y > 0 => println!("{}", y),
+        Some(y) if y > 0 => println!("again {}", y),
+        _ => (),
+    }
+
+    match x {
+        _ if true => println!("always"),
+        None => println!("none"),
+        Some(_) => (),
+    }
+
+    let t = (1, 2);
+    match t {
+        (0, b) | (b, 0) if b > 1 => (),
+        (0, b) | (b, 0) if b > 1 => println!("{}", b),
+        _ => (),
+    }
+
+    // not linted, the guards differ
+    match x {
+        Some(y) if y > 0 => println!("{}", y),
+        Some(y) if y < 0 => println!("{}", y),
+        _ => (),
+    }
+
+    // not linted, the patterns differ
+    match x {
+        Some(0) if true => (),
+        Some(_) => (),
+        None => (),
+    }
+
+    // not linted, `unreachable_patterns` already reports arms after a catch-all
+    match x {
+        _ => (),
+        Some(y) if y > 0 => (),
+    }
+
+    // not linted, the binding is guarded
+    match x {
+        y if y.is_some() => println!("{:?}", y),
+        None => println!("none"),
+        Some(_) => (),
+    }
+}
@@ -0,0 +1,17 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(unreadable_literal)]
+
+fn main() {
+    let _ = 1000000;
+    let _ = 1234567u64;
+    let _ = 123456.7890123;
+    let _ = 0xDEADBEEFu32;
+    let _ = 0b10101010;
+
+    // not linted
+    let _ = 12345;
+    let _ = 1_000000;
+    let _ = 0xFFFF;
+    let _ = 1.5e10;
+}
@@ -0,0 +1,22 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(unused_collect)]
+#![allow(unused)]
+
+fn parse(s: &str) -> Result<u32, std::num::ParseIntError> {
+    s.parse()
+}
+
+fn main() {
+    let v = vec!["1", "2", "x"];
+    let mut out = vec![];
+
+    v.iter().map(|x| out.push(x)).collect::<Vec<_>>();
+    v.iter().map(|x| parse(x)).collect::<Result<Vec<_>, _>>();
+    v.iter().map(|x| parse(x).ok()).collect::<Option<Vec<_>>>();
+
+    // these are fine
+    let _ = v.iter().map(|x| parse(x)).collect::<Result<Vec<_>, _>>();
+    let _w: Option<Vec<_>> = v.iter().map(|x| parse(x).ok()).collect();
+}
@@ -0,0 +1,20 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(unused_enumerate_index)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    for (_, x) in v.iter().enumerate() {
+        println!("{}", x);
+    }
+
+    for (_, x) in v.into_iter().enumerate() {
+        println!("{}", x);
+    }
+
+    // not linted, the index is used
+    let v = vec![1, 2, 3];
+    for (i, x) in v.iter().enumerate() {
+        println!("{} {}", i, x);
+    }
+}
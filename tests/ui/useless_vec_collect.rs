@@ -0,0 +1,21 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(useless_vec)]
+
+use std::collections::{HashMap, HashSet};
+
+fn main() {
+    let _: HashSet<_> = vec![1, 2, 3].iter().collect();
+    let _: HashSet<u8> = vec![0; 8].iter().cloned().collect();
+    let _: HashMap<_, _> = vec![(1, "one"), (2, "two")].iter().cloned().collect();
+
+    // `into_iter` on an array would yield references
+    let _: HashSet<_> = vec![1, 2, 3].into_iter().collect();
+
+    // `[String::new(); 3]` would require `String` to be `Copy`
+    let _: Vec<&String> = vec![String::new(); 3].iter().collect();
+
+    let v = vec![1, 2, 3];
+    let _: HashSet<_> = v.iter().collect();
+}
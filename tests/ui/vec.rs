@@ -71,4 +71,8 @@ fn main() {
     for a in vec![NonCopy, NonCopy] {
         println!("{:?}", a);
     }
+
+    on_mut_slice(&mut vec![1, 2, 3]);
 }
+
+fn on_mut_slice(_: &mut [i32]) {}
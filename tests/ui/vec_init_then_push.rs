@@ -0,0 +1,28 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+
+#![deny(vec_init_then_push)]
+
+fn main() {
+    let mut v = Vec::new();
+    v.push(1);
+    v.push(2);
+
+    let mut w: Vec<String> = Vec::new();
+    w.push(String::new());
+    println!("{:?}", w);
+
+    // only the pushes before `v` is used can be merged
+    let mut v = Vec::new();
+    v.push(1);
+    v.push(v.len());
+
+    // not immediately pushed to
+    let mut v = Vec::new();
+    if v.is_empty() {
+        v.push(1);
+    }
+
+    let v: Vec<u8> = Vec::new();
+    println!("{:?}", v);
+}
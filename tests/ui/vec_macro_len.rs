@@ -0,0 +1,16 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(vec_macro_len)]
+
+const N: usize = 4;
+
+fn main() {
+    let _ = vec![1, 2, 3].len();
+    let _ = vec![0u8; N * 2].len();
+    let _ = vec![1].is_empty();
+
+    // not linted, the length is not known or this is not a `vec!`
+    let n = 3;
+    let _ = vec![0u8; n].len();
+    let _ = Vec::<u8>::new().is_empty();
+}
@@ -0,0 +1,39 @@
+#![feature(plugin)]
+#![plugin(clippy)]
+#![deny(while_let_on_new_iterator, while_let_on_iterator)]
+#![allow(unused_variables)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+
+    while let Some(x) = v.iter().next() {
+        break;
+    }
+    while let Some(&1) = v.iter().skip(1).next() {
+        break;
+    }
+    let s = "abc";
+    while let Some(c) = s.chars().next() {
+        break;
+    }
+
+    // a stored iterator is linted by `while_let_on_iterator`
+    let mut it = v.iter();
+    while let Some(x) = it.next() {
+        println!("{}", x);
+    }
+
+    // not linted, the iterator is stored
+    let mut it = v.iter();
+    while let Some(&1) = it.next() {}
+    let mut it = v.iter();
+    while let Some(x) = (&mut it).next() {
+        break;
+    }
+    while let Some(x) = it.by_ref().next() {
+        break;
+    }
+    while let Some(x) = it.by_ref().skip(1).next() {
+        break;
+    }
+}
@@ -193,3 +193,22 @@ fn refutable() {
     for &Option::None in b.next() {}
     // */
 }
+
+fn busy_wait(flag: &std::sync::atomic::AtomicBool) {
+    while !flag.load(std::sync::atomic::Ordering::SeqCst) {}
+}
+
+fn counting_condition() {
+    let mut i = 0;
+    while {
+        i += 1;
+        i < 3
+    } {}
+}
+
+fn progressing_conditions() {
+    let mut it = 0..3;
+    while it.next().is_some() {}
+    let mut stack = vec![1, 2];
+    while stack.pop().is_some() {}
+}